@@ -1,5 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use hifitime::{Duration, Epoch, Unit};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondSlice};
+use hifitime::{Duration, Epoch, TimeScale, Unit};
 
 #[allow(unused_must_use)]
 pub fn criterion_benchmark(c: &mut Criterion) {
@@ -59,6 +60,44 @@ pub fn criterion_benchmark(c: &mut Criterion) {
         })
     });
 
+    let mut group = c.benchmark_group("UTC conversion");
+    group.throughput(Throughput::Elements(1));
+
+    group.bench_function("UTC to TAI", |b| {
+        let e = Epoch::from_gregorian_utc_hms(2015, 2, 7, 11, 22, 33);
+        b.iter(|| black_box(e).to_time_scale(TimeScale::TAI));
+    });
+
+    group.bench_function("TAI to UTC", |b| {
+        let e = Epoch::from_gregorian_tai_hms(2015, 2, 7, 11, 22, 33);
+        b.iter(|| black_box(e).to_time_scale(TimeScale::UTC));
+    });
+
+    group.bench_function("TAI to UTC in 1962", |b| {
+        let e = Epoch::from_gregorian_tai_hms(1962, 2, 7, 11, 22, 33);
+        b.iter(|| black_box(e).to_time_scale(TimeScale::UTC));
+    });
+
+    // Lookup of the leap seconds with the binary search, compared to the linear scan of the iterator over the leap seconds
+    group.bench_function("leap seconds binary search", |b| {
+        let e = Epoch::from_gregorian_utc_hms(2015, 2, 7, 11, 22, 33);
+        b.iter(|| black_box(e).leap_seconds_with(true, LeapSecondSlice::latest()));
+    });
+
+    group.bench_function("leap seconds linear scan", |b| {
+        let e = Epoch::from_gregorian_utc_hms(2015, 2, 7, 11, 22, 33);
+        b.iter(|| {
+            let e = black_box(e);
+            LatestLeapSeconds::default()
+                .rev()
+                .filter(|leap_second| leap_second.announced_by_iers)
+                .find(|leap_second| e.duration >= leap_second.timestamp_tai_s * Unit::Second)
+                .map(|leap_second| leap_second.delta_at)
+        });
+    });
+
+    group.finish();
+
     #[cfg(feature = "std")]
     {
         c.bench_function("RFC3339 with seconds", |b| {
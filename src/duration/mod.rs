@@ -50,6 +50,7 @@ pub const NANOSECONDS_PER_MINUTE: u64 = 60 * NANOSECONDS_PER_SECOND;
 pub const NANOSECONDS_PER_HOUR: u64 = 60 * NANOSECONDS_PER_MINUTE;
 pub const NANOSECONDS_PER_DAY: u64 = 24 * NANOSECONDS_PER_HOUR;
 pub const NANOSECONDS_PER_CENTURY: u64 = DAYS_PER_CENTURY_U64 * NANOSECONDS_PER_DAY;
+pub const NANOSECONDS_PER_SOL: u64 = 88_775_244_147_000;
 
 pub mod ops;
 
@@ -156,6 +157,12 @@ impl Duration {
         nanoseconds: 1,
     };
 
+    /// One Martian mean solar day (sol), i.e. 88,775.244147 seconds
+    pub const SOL: Self = Self {
+        centuries: 0,
+        nanoseconds: NANOSECONDS_PER_SOL,
+    };
+
     /// Minimum positive duration is one nanoseconds
     pub const MIN_POSITIVE: Self = Self::EPSILON;
 
@@ -463,32 +470,29 @@ impl Duration {
     /// Decomposes a Duration in its sign, days, hours, minutes, seconds, ms, us, ns
     #[must_use]
     pub fn decompose(&self) -> (i8, u64, u64, u64, u64, u64, u64, u64) {
-        let mut me = *self;
-        let sign = me.signum();
-        me = me.abs();
-        let days = me.to_unit(Unit::Day).floor();
-        me -= days.days();
-        let hours = me.to_unit(Unit::Hour).floor();
-        me -= hours.hours();
-        let minutes = me.to_unit(Unit::Minute).floor();
-        me -= minutes.minutes();
-        let seconds = me.to_unit(Unit::Second).floor();
-        me -= seconds.seconds();
-        let milliseconds = me.to_unit(Unit::Millisecond).floor();
-        me -= milliseconds.milliseconds();
-        let microseconds = me.to_unit(Unit::Microsecond).floor();
-        me -= microseconds.microseconds();
-        let nanoseconds = me.to_unit(Unit::Nanosecond).round();
-
-        // Everything should fit in the expected types now
+        let sign = self.signum();
+        // Integer arithmetic such that the last nanosecond of a day is not rounded into the next one.
+        let mut nanoseconds = self.abs().total_nanoseconds().unsigned_abs();
+        let mut split = |unit_ns: u64| -> u64 {
+            let count = nanoseconds / u128::from(unit_ns);
+            nanoseconds %= u128::from(unit_ns);
+            count as u64
+        };
+        let days = split(NANOSECONDS_PER_DAY);
+        let hours = split(NANOSECONDS_PER_HOUR);
+        let minutes = split(NANOSECONDS_PER_MINUTE);
+        let seconds = split(NANOSECONDS_PER_SECOND);
+        let milliseconds = split(NANOSECONDS_PER_MILLISECOND);
+        let microseconds = split(NANOSECONDS_PER_MICROSECOND);
+
         (
             sign,
-            days as u64,
-            hours as u64,
-            minutes as u64,
-            seconds as u64,
-            milliseconds as u64,
-            microseconds as u64,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
             nanoseconds as u64,
         )
     }
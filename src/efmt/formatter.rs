@@ -10,7 +10,7 @@
 
 use core::fmt;
 
-use crate::{parser::Token, Duration, Epoch, TimeScale};
+use crate::{parser::Token, CustomTimeScale, Duration, Epoch, TimeScale, Unit};
 
 use super::format::Format;
 
@@ -76,12 +76,15 @@ impl Item {
     }
 }
 
-#[cfg_attr(kani, derive(kani::Arbitrary))]
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Formatter {
     epoch: Epoch,
     offset: Duration,
     format: Format,
+    /// Name of the custom time scale this epoch is expressed in, if any.
+    custom_time_scale_name: Option<&'static str>,
+    /// Whether this epoch in UTC was converted from an instant within a leap second, printed as the 60th second.
+    leap_second: bool,
 }
 
 impl Formatter {
@@ -90,6 +93,8 @@ impl Formatter {
             epoch,
             offset: Duration::ZERO,
             format,
+            custom_time_scale_name: None,
+            leap_second: false,
         }
     }
 
@@ -98,11 +103,33 @@ impl Formatter {
             epoch: epoch + offset,
             offset,
             format,
+            custom_time_scale_name: None,
+            leap_second: false,
         }
     }
 
+    /// Formats the provided epoch in the provided time scale. In UTC, the instants within a leap second are printed as `23:59:60`.
     pub fn to_time_scale(epoch: Epoch, format: Format, time_scale: TimeScale) -> Self {
-        Self::new(epoch.to_time_scale(time_scale), format)
+        Self {
+            leap_second: time_scale == TimeScale::UTC && epoch.is_within_leap_second(),
+            ..Self::new(epoch.to_time_scale(time_scale), format)
+        }
+    }
+
+    /// Formats the provided epoch in the provided custom time scale, whose name is printed for the time scale token.
+    pub fn to_custom_time_scale<T: CustomTimeScale + ?Sized>(
+        epoch: Epoch,
+        format: Format,
+        time_scale: &T,
+    ) -> Self {
+        // The reading of the custom clock is shown on the TAI calendar.
+        let custom_epoch = Epoch::from_tai_duration(
+            time_scale.reference_epoch().to_tai_duration() + epoch.to_custom_duration(time_scale),
+        );
+        Self {
+            custom_time_scale_name: Some(time_scale.name()),
+            ..Self::new(custom_epoch, format)
+        }
     }
 
     pub fn set_timezone(&mut self, offset: Duration) {
@@ -110,6 +137,20 @@ impl Formatter {
     }
 }
 
+#[cfg(kani)]
+impl kani::Arbitrary for Formatter {
+    fn any() -> Self {
+        // Custom time scale names are static strings and cannot be generated.
+        Self {
+            epoch: kani::any(),
+            offset: kani::any(),
+            format: kani::any(),
+            custom_time_scale_name: None,
+            leap_second: false,
+        }
+    }
+}
+
 impl fmt::Display for Formatter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // We make sure to only call this as needed.
@@ -129,8 +170,16 @@ impl fmt::Display for Formatter {
 
         if self.format.need_gregorian() {
             // This is a specific branch so we don't recompute the gregorian information for each token.
-            let (y, mm, dd, hh, min, s, nanos) =
-                Epoch::compute_gregorian(self.epoch.duration, self.epoch.time_scale);
+            let (y, mm, dd, hh, min, s, nanos) = if self.leap_second {
+                // The UTC reading of the leap second is that of the following second.
+                let (y, mm, dd, hh, min, s, nanos) = Epoch::compute_gregorian(
+                    self.epoch.duration - Unit::Second,
+                    self.epoch.time_scale,
+                );
+                (y, mm, dd, hh, min, s + 1, nanos)
+            } else {
+                Epoch::compute_gregorian(self.epoch.duration, self.epoch.time_scale)
+            };
             // And format.
             for (i, maybe_item) in self
                 .format
@@ -202,7 +251,10 @@ impl fmt::Display for Formatter {
                         return Err(fmt::Error);
                     }
                     Token::Timescale => {
-                        if !item.optional || self.epoch.time_scale != TimeScale::UTC {
+                        if let Some(name) = self.custom_time_scale_name {
+                            write_sep(f, i, &self.format)?;
+                            write!(f, "{name}")?;
+                        } else if !item.optional || self.epoch.time_scale != TimeScale::UTC {
                             write_sep(f, i, &self.format)?;
                             write!(f, "{}", self.epoch.time_scale)?;
                         }
@@ -273,7 +325,10 @@ impl fmt::Display for Formatter {
                         return Err(fmt::Error);
                     }
                     Token::Timescale => {
-                        if !item.optional || self.epoch.time_scale != TimeScale::UTC {
+                        if let Some(name) = self.custom_time_scale_name {
+                            write_sep(f, i, &self.format)?;
+                            write!(f, "{name}")?;
+                        } else if !item.optional || self.epoch.time_scale != TimeScale::UTC {
                             write_sep(f, i, &self.format)?;
                             write!(f, "{}", self.epoch.time_scale)?;
                         }
@@ -0,0 +1,215 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::{fs::File, io::Read, path::Path};
+
+use crate::{Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit};
+
+/// The UTC - UTC(k) values of a laboratory k, as published in section 1 of BIPM Circular T.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UtcRealization {
+    /// Acronym of the laboratory, e.g. `USNO` for UTC(USNO).
+    pub laboratory: String,
+    /// UTC - UTC(k) in nanoseconds at each date of the Circular, or None if the laboratory did not report a value.
+    pub offsets_ns: Vec<Option<f64>>,
+}
+
+#[derive(Clone, Debug, Default)]
+/// A provider of the UTC(k) realizations of the national laboratories which parses section 1 of the monthly BIPM Circular T,
+/// as published on <https://www.bipm.org/en/time-ftp/circular-t> .
+///
+/// Circular T gives UTC - UTC(k) every five days at 0h UTC, which this provider linearly interpolates.
+pub struct CircularT {
+    mjd_utc: Vec<f64>,
+    realizations: Vec<UtcRealization>,
+}
+
+impl CircularT {
+    /// Builds a UTC(k) provider from the provided Circular T file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening Circular T file",
+                })
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading Circular T file",
+            });
+        }
+
+        Self::from_file_str(&contents)
+    }
+
+    /// Builds a UTC(k) provider from the contents of a Circular T file.
+    ///
+    /// Section 1 starts with the line listing the integer Modified Julian Dates of the Circular, and ends with the header of section 2.
+    /// Each laboratory line starts with its acronym, optionally followed by its location in parentheses, and then by one value
+    /// in nanoseconds per date, where a dash marks a missing value. Any further column (uncertainties, notes) is ignored.
+    pub fn from_file_str(contents: &str) -> Result<Self, HifitimeError> {
+        let mut me = Self::default();
+
+        for line in contents.lines() {
+            let mut tokens = line.split_whitespace();
+            let first = match tokens.next() {
+                Some(first) => first,
+                None => continue,
+            };
+
+            if me.mjd_utc.is_empty() {
+                // Skip the header of the Circular until the list of dates of section 1.
+                if first == "MJD" {
+                    // The dates may be followed by the headers of the uncertainties.
+                    me.mjd_utc = tokens
+                        .map_while(|mjd| lexical_core::parse::<u32>(mjd.as_bytes()).ok())
+                        .map(f64::from)
+                        .collect();
+                    if me.mjd_utc.is_empty() {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "Circular T dates should be integer MJDs",
+                        });
+                    }
+                }
+                continue;
+            }
+
+            if first.starts_with(|c: char| c.is_ascii_digit()) {
+                // Start of section 2
+                break;
+            } else if matches!(first, "MJD" | "Date" | "Laboratory" | "Notes:")
+                || first.starts_with('(')
+            {
+                continue;
+            }
+
+            // Skip the location of the laboratory, if any.
+            let values = match line.split_once(')') {
+                Some((lab, values)) if lab.contains('(') => values,
+                _ => line.trim_start().trim_start_matches(first),
+            };
+
+            let mut offsets_ns = Vec::with_capacity(me.mjd_utc.len());
+            for value in values.split_whitespace().take(me.mjd_utc.len()) {
+                if value == "-" {
+                    offsets_ns.push(None);
+                } else {
+                    match lexical_core::parse(value.as_bytes()) {
+                        Ok(value) => offsets_ns.push(Some(value)),
+                        Err(_) => {
+                            return Err(HifitimeError::Parse {
+                                source: ParsingError::ValueError,
+                                details: "Circular T value is not numeric",
+                            })
+                        }
+                    }
+                }
+            }
+
+            if offsets_ns.len() != me.mjd_utc.len() {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "Circular T laboratory should have one value per date",
+                });
+            }
+
+            me.realizations.push(UtcRealization {
+                laboratory: first.to_string(),
+                offsets_ns,
+            });
+        }
+
+        if me.realizations.is_empty() {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::NothingToParse,
+                details: "Circular T file has no UTC(k) values",
+            });
+        }
+
+        Ok(me)
+    }
+
+    /// Returns the Modified Julian Dates in UTC of the values of this Circular.
+    pub fn mjd_utc(&self) -> &[f64] {
+        &self.mjd_utc
+    }
+
+    /// Returns the UTC(k) realizations of all of the laboratories of this Circular.
+    pub fn realizations(&self) -> &[UtcRealization] {
+        &self.realizations
+    }
+
+    /// Returns the UTC(k) realization of the provided laboratory, if it is part of this Circular.
+    pub fn realization(&self, laboratory: &str) -> Option<&UtcRealization> {
+        self.realizations
+            .iter()
+            .find(|realization| realization.laboratory == laboratory)
+    }
+
+    /// Returns UTC - UTC(k) linearly interpolated at the provided MJD in UTC, or None if the laboratory did not report values around that date.
+    fn offset_at(&self, laboratory: &str, mjd_utc: f64) -> Option<Duration> {
+        let realization = self.realization(laboratory)?;
+        let points = || {
+            self.mjd_utc
+                .iter()
+                .zip(&realization.offsets_ns)
+                .filter_map(|(mjd, offset_ns)| offset_ns.map(|offset_ns| (*mjd, offset_ns)))
+        };
+
+        let (prev_mjd, prev_ns) = points().take_while(|(mjd, _)| *mjd <= mjd_utc).last()?;
+        let offset_ns = if prev_mjd == mjd_utc {
+            prev_ns
+        } else {
+            let (next_mjd, next_ns) = points().find(|(mjd, _)| *mjd > mjd_utc)?;
+            prev_ns + (mjd_utc - prev_mjd) / (next_mjd - prev_mjd) * (next_ns - prev_ns)
+        };
+        Some(Unit::Nanosecond * offset_ns)
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns UTC - UTC(k) of the provided laboratory at this epoch, linearly interpolated from the provided Circular T,
+    /// or None if this epoch is outside of the values reported by that laboratory.
+    pub fn utc_k_offset(&self, laboratory: &str, provider: &CircularT) -> Option<Duration> {
+        provider.offset_at(laboratory, self.to_mjd_utc_days())
+    }
+
+    #[must_use]
+    /// Returns this epoch as realized by UTC(k) of the provided laboratory, i.e. an epoch in UTC whose reading is that of UTC(k),
+    /// or None if this epoch is outside of the values reported by that laboratory.
+    pub fn to_utc_k(&self, laboratory: &str, provider: &CircularT) -> Option<Self> {
+        let offset = self.utc_k_offset(laboratory, provider)?;
+        Some(Self::from_duration(
+            self.to_utc_duration() - offset,
+            TimeScale::UTC,
+        ))
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided UTC(k) duration since 1900 January 01 at midnight, or None if outside of the values reported by that laboratory.
+    pub fn from_utc_k_duration(
+        duration: Duration,
+        laboratory: &str,
+        provider: &CircularT,
+    ) -> Option<Self> {
+        // UTC - UTC(k) is at most a few tens of nanoseconds, so a single iteration suffices.
+        let utc_k_as_utc = Self::from_utc_duration(duration);
+        let offset = utc_k_as_utc.utc_k_offset(laboratory, provider)?;
+        Some(utc_k_as_utc + offset)
+    }
+}
@@ -0,0 +1,335 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::TT_OFFSET_MS;
+use crate::leap_seconds::{LeapSecond, LeapSecondSlice, LeapSecondTable};
+use crate::{Duration, Epoch, Unit};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+/// Observed ΔT in seconds on January 1st of each year since 2005, i.e. 32.184 s + (TAI - UTC) - (UT1 - UTC),
+/// as published by the USNO on <https://maia.usno.navy.mil/ser7/deltat.data>.
+const DELTA_T_OBSERVED_S: [(f64, f64); 20] = [
+    (2005.0, 64.6876),
+    (2006.0, 64.8452),
+    (2007.0, 65.1464),
+    (2008.0, 65.4573),
+    (2009.0, 65.7768),
+    (2010.0, 66.0699),
+    (2011.0, 66.3246),
+    (2012.0, 66.6030),
+    (2013.0, 66.9069),
+    (2014.0, 67.2810),
+    (2015.0, 67.6439),
+    (2016.0, 68.1024),
+    (2017.0, 68.5927),
+    (2018.0, 68.9677),
+    (2019.0, 69.2202),
+    (2020.0, 69.3612),
+    (2021.0, 69.3593),
+    (2022.0, 69.2945),
+    (2023.0, 69.2039),
+    (2024.0, 69.1752),
+];
+
+/// Year from which ΔT is known to a tenth of a second, when atomic time became available.
+const DELTA_T_OBSERVED_START_YEAR: f64 = 1960.0;
+
+/// Uncertainty of ΔT in seconds since 1960 until the last observed value: the polynomials of Espenak & Meeus (2006)
+/// are within 0.06 s of the observed values from 1960 until 2005, and the yearly observed values are linearly interpolated afterwards.
+const DELTA_T_OBSERVED_UNCERTAINTY_S: f64 = 0.1;
+
+/// Uncertainty of ΔT in seconds when computed from the leap seconds, i.e. the maximum value of |UT1 - UTC|.
+const DELTA_T_LEAP_SECONDS_UNCERTAINTY_S: f64 = 0.9;
+
+/// Uncertainty of ΔT in seconds from Morrison & Stephenson (2004), as (year, seconds), linearly interpolated.
+const DELTA_T_UNCERTAINTY_S: [(f64, f64); 25] = [
+    (-500.0, 430.0),
+    (-400.0, 390.0),
+    (-300.0, 360.0),
+    (-200.0, 330.0),
+    (-100.0, 290.0),
+    (0.0, 260.0),
+    (100.0, 240.0),
+    (200.0, 210.0),
+    (300.0, 180.0),
+    (400.0, 160.0),
+    (500.0, 140.0),
+    (600.0, 120.0),
+    (700.0, 100.0),
+    (800.0, 80.0),
+    (900.0, 70.0),
+    (1000.0, 55.0),
+    (1100.0, 40.0),
+    (1200.0, 30.0),
+    (1300.0, 20.0),
+    (1400.0, 20.0),
+    (1500.0, 20.0),
+    (1600.0, 20.0),
+    (1700.0, 5.0),
+    (1800.0, 1.0),
+    (1900.0, 1.0),
+];
+
+/// Returns ΔT in seconds at the provided decimal year using the polynomials of Espenak & Meeus (2006).
+fn espenak_meeus_delta_t(y: f64) -> f64 {
+    // Evaluates the polynomial of the provided coefficients, in increasing powers of x.
+    let poly = |x: f64, coeffs: &[f64]| coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c);
+    // Long term parabola of Morrison & Stephenson (2004).
+    let parabola = |y: f64| -20.0 + 32.0 * ((y - 1820.0) / 100.0).powi(2);
+
+    if y < -500.0 {
+        parabola(y)
+    } else if y < 500.0 {
+        poly(
+            y / 100.0,
+            &[
+                10_583.6,
+                -1_014.41,
+                33.783_11,
+                -5.952_053,
+                -0.179_845_2,
+                0.022_174_192,
+                0.009_031_652_1,
+            ],
+        )
+    } else if y < 1600.0 {
+        poly(
+            (y - 1000.0) / 100.0,
+            &[
+                1_574.2,
+                -556.01,
+                71.234_72,
+                0.319_781,
+                -0.850_346_3,
+                -0.005_050_998,
+                0.008_357_207_3,
+            ],
+        )
+    } else if y < 1700.0 {
+        poly(y - 1600.0, &[120.0, -0.9808, -0.01532, 1.0 / 7129.0])
+    } else if y < 1800.0 {
+        poly(
+            y - 1700.0,
+            &[8.83, 0.1603, -0.005_928_5, 0.000_133_36, -1.0 / 1_174_000.0],
+        )
+    } else if y < 1860.0 {
+        poly(
+            y - 1800.0,
+            &[
+                13.72,
+                -0.332_447,
+                0.006_861_2,
+                0.004_111_6,
+                -0.000_374_36,
+                0.000_012_127_2,
+                -0.000_000_169_9,
+                0.000_000_000_875,
+            ],
+        )
+    } else if y < 1900.0 {
+        poly(
+            y - 1860.0,
+            &[
+                7.62,
+                0.5737,
+                -0.251_754,
+                0.016_806_68,
+                -0.000_447_362_4,
+                1.0 / 233_174.0,
+            ],
+        )
+    } else if y < 1920.0 {
+        poly(
+            y - 1900.0,
+            &[-2.79, 1.494_119, -0.059_893_9, 0.006_196_6, -0.000_197],
+        )
+    } else if y < 1941.0 {
+        poly(y - 1920.0, &[21.20, 0.844_93, -0.076_100, 0.002_093_6])
+    } else if y < 1961.0 {
+        poly(y - 1950.0, &[29.07, 0.407, -1.0 / 233.0, 1.0 / 2547.0])
+    } else if y < 1986.0 {
+        poly(y - 1975.0, &[45.45, 1.067, -1.0 / 260.0, -1.0 / 718.0])
+    } else if y < 2005.0 {
+        poly(
+            y - 2000.0,
+            &[
+                63.86,
+                0.3345,
+                -0.060_374,
+                0.001_727_5,
+                0.000_651_814,
+                0.000_023_735_99,
+            ],
+        )
+    } else if y < 2050.0 {
+        poly(y - 2000.0, &[62.92, 0.322_17, 0.005_589])
+    } else if y < 2150.0 {
+        parabola(y) - 0.5628 * (2150.0 - y)
+    } else {
+        parabola(y)
+    }
+}
+
+/// Returns the observed ΔT in seconds at the provided decimal year, linearly interpolated, or None outside of the observed values.
+fn observed_delta_t(y: f64) -> Option<f64> {
+    let (first, last) = (
+        DELTA_T_OBSERVED_S[0],
+        DELTA_T_OBSERVED_S[DELTA_T_OBSERVED_S.len() - 1],
+    );
+    if y < first.0 || y > last.0 {
+        return None;
+    }
+    let idx = DELTA_T_OBSERVED_S.partition_point(|(year, _)| *year <= y);
+    if idx == DELTA_T_OBSERVED_S.len() {
+        return Some(last.1);
+    }
+    let (prev_y, prev_s) = DELTA_T_OBSERVED_S[idx - 1];
+    let (next_y, next_s) = DELTA_T_OBSERVED_S[idx];
+    Some(prev_s + (y - prev_y) / (next_y - prev_y) * (next_s - prev_s))
+}
+
+/// Returns the last leap second of the installed leap seconds, or of the built-in ones.
+fn last_leap_second() -> Option<LeapSecond> {
+    #[cfg(feature = "std")]
+    {
+        if let Some(installed) = super::leap_seconds::installed_leap_seconds() {
+            return installed.last().copied();
+        }
+    }
+    LeapSecondSlice::latest().as_slice().last().copied()
+}
+
+impl Epoch {
+    /// Returns the decimal year of this epoch in TT, as used by the ΔT models.
+    fn delta_t_decimal_year(&self) -> f64 {
+        2000.0 + self.to_tt_centuries_j2k() * 100.0
+    }
+
+    /// Returns the decimal year until which ΔT is known, and ΔT in seconds at that time.
+    ///
+    /// This is the last observed value, unless the installed leap seconds include a more recent leap second,
+    /// in which case UTC is known to be within 0.9 s of UT1 until then.
+    fn delta_t_known_end() -> (f64, f64, bool) {
+        let (observed_y, observed_s) = DELTA_T_OBSERVED_S[DELTA_T_OBSERVED_S.len() - 1];
+        if let Some(leap_second) = last_leap_second() {
+            let leap_y = Self::from_utc_duration(leap_second.timestamp_tai_s * Unit::Second)
+                .delta_t_decimal_year();
+            if leap_y > observed_y {
+                let delta_t_s = TT_OFFSET_MS as f64 * 1e-3 + leap_second.delta_at;
+                return (leap_y, delta_t_s, true);
+            }
+        }
+        (observed_y, observed_s, false)
+    }
+
+    #[must_use]
+    /// Returns an estimate of ΔT = TT - UT1 at this epoch, for any year, without requiring Earth orientation parameters.
+    ///
+    /// + Until 2005, ΔT uses the polynomials of Espenak & Meeus (2006) as published on <https://eclipse.gsfc.nasa.gov/SEhelp/deltatpoly2004.html>,
+    ///   which follow the long term parabola of Morrison & Stephenson (2004) before -500, and fit the observed values within 0.06 s since 1960.
+    /// + From 2005, ΔT is linearly interpolated from the yearly values observed by the IERS until the last one (2024).
+    /// + If the installed leap seconds include a more recent leap second, ΔT is computed from the leap seconds until then, i.e. `32.184 s + ΔAT`.
+    /// + Afterwards, ΔT extrapolates the Espenak & Meeus polynomials, offset to be continuous with the last known value.
+    ///
+    /// Refer to [Epoch::delta_t_uncertainty] for the uncertainty of this estimate. If you have Earth orientation parameters, use the UT1 time scale instead.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeScale, TimeUnits};
+    ///
+    /// // Observed by the IERS
+    /// let epoch = Epoch::from_gregorian_at_noon(2020, 1, 1, TimeScale::TT);
+    /// assert!((epoch.delta_t_estimate() - 69.3612.seconds()).abs() < 1.milliseconds());
+    ///
+    /// // For historical records
+    /// let epoch = Epoch::from_gregorian_at_noon(1000, 1, 1, TimeScale::TT);
+    /// assert!((epoch.delta_t_estimate() - 1_574.2.seconds()).abs() < 1.seconds());
+    /// ```
+    pub fn delta_t_estimate(&self) -> Duration {
+        let y = self.delta_t_decimal_year();
+        if y < DELTA_T_OBSERVED_S[0].0 {
+            return Unit::Second * espenak_meeus_delta_t(y);
+        }
+        if let Some(delta_t_s) = observed_delta_t(y) {
+            return Unit::Second * delta_t_s;
+        }
+        let (end_y, end_s, _) = Self::delta_t_known_end();
+        if y <= end_y {
+            if let Some(delta_at) = self.leap_seconds(false) {
+                return TT_OFFSET_MS * Unit::Millisecond + delta_at * Unit::Second;
+            }
+        }
+        Unit::Second * (espenak_meeus_delta_t(y) + end_s - espenak_meeus_delta_t(end_y))
+    }
+
+    #[must_use]
+    /// Returns the one sigma uncertainty of [Epoch::delta_t_estimate] at this epoch.
+    ///
+    /// + Before -500, this is the uncertainty of the parabola of Morrison & Stephenson (2004), i.e. `0.8 * ((year - 1820) / 100)^2` seconds.
+    /// + From -500 until 1960, this is interpolated from the uncertainties tabulated by Morrison & Stephenson (2004), with a floor of one second.
+    /// + From 1960 until the last observed value, this is 0.1 seconds, the error of the polynomials and of the interpolation.
+    /// + Until the last installed leap second, if more recent, this is 0.9 seconds, the maximum difference between UTC and UT1.
+    /// + Afterwards, this is a rough estimate which grows by 0.25 seconds per year for the decadal fluctuations of the rotation of the Earth,
+    ///   on top of the long term uncertainty of Morrison & Stephenson (2004).
+    pub fn delta_t_uncertainty(&self) -> Duration {
+        let y = self.delta_t_decimal_year();
+        let (end_y, _, from_leap_seconds) = Self::delta_t_known_end();
+        let end_sigma_s = if from_leap_seconds {
+            DELTA_T_LEAP_SECONDS_UNCERTAINTY_S
+        } else {
+            DELTA_T_OBSERVED_UNCERTAINTY_S
+        };
+        let observed_end_y = DELTA_T_OBSERVED_S[DELTA_T_OBSERVED_S.len() - 1].0;
+        let sigma_s = if y > end_y {
+            let dy = y - end_y;
+            end_sigma_s + 0.25 * dy + 0.8 * (dy / 100.0).powi(2)
+        } else if y > observed_end_y {
+            DELTA_T_LEAP_SECONDS_UNCERTAINTY_S
+        } else if y >= DELTA_T_OBSERVED_START_YEAR {
+            DELTA_T_OBSERVED_UNCERTAINTY_S
+        } else if y < DELTA_T_UNCERTAINTY_S[0].0 {
+            0.8 * ((y - 1820.0) / 100.0).powi(2)
+        } else {
+            let idx = DELTA_T_UNCERTAINTY_S.partition_point(|(year, _)| *year <= y);
+            if idx == DELTA_T_UNCERTAINTY_S.len() {
+                DELTA_T_UNCERTAINTY_S[idx - 1].1
+            } else {
+                let (prev_y, prev_s) = DELTA_T_UNCERTAINTY_S[idx - 1];
+                let (next_y, next_s) = DELTA_T_UNCERTAINTY_S[idx];
+                prev_s + (y - prev_y) / (next_y - prev_y) * (next_s - prev_s)
+            }
+        };
+        Unit::Second * sigma_s
+    }
+
+    #[must_use]
+    /// Returns this epoch as a duration past J1900 counted in UT1, estimated with [Epoch::delta_t_estimate].
+    ///
+    /// Use [Epoch::from_ut1_estimate_duration] for the reverse conversion, since an Epoch in the UT1 time scale is converted with the installed UT1 provider instead.
+    pub fn to_ut1_estimate_duration(&self) -> Duration {
+        self.to_tt_duration() - self.delta_t_estimate()
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight, using [Epoch::delta_t_estimate].
+    ///
+    /// The time scale of this Epoch is set to TT, such that the ΔT estimate is kept.
+    pub fn from_ut1_estimate_duration(duration: Duration) -> Self {
+        // ΔT varies by less than a millisecond per day, so this fixed point iteration converges within a few steps.
+        let mut epoch = Self::from_tt_duration(duration);
+        for _ in 0..3 {
+            epoch = Self::from_tt_duration(duration + epoch.delta_t_estimate());
+        }
+        epoch
+    }
+}
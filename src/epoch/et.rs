@@ -0,0 +1,133 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use super::{NAIF_EB, NAIF_K, NAIF_M0, NAIF_M1, TT_OFFSET_MS};
+use crate::{Duration, Epoch, TimeScale, TimeUnits, Unit};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+/// The constants of the NAIF SPICE model of Ephemeris Time (ET), as defined by the `DELTET` variables of a leap second kernel:
+///
+/// `ET - TAI = DELTA_T_A + K * sin(E)`, where `E = M + EB * sin(M)` and `M = M0 + M1 * seconds past J2000`.
+///
+/// The default model uses the constants of `naif0012.tls`, which are those used by [TimeScale::ET].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct EtModel {
+    /// `DELTET/DELTA_T_A`: difference between TT and TAI, in seconds.
+    pub delta_t_a: f64,
+    /// `DELTET/K`: amplitude of the periodic term, in seconds.
+    pub k: f64,
+    /// `DELTET/EB`: eccentricity of the heliocentric orbit of the Earth-Moon barycenter.
+    pub eb: f64,
+    /// First value of `DELTET/M`: mean anomaly of the heliocentric orbit of the Earth-Moon barycenter at J2000, in radians.
+    pub m0: f64,
+    /// Second value of `DELTET/M`: rate of this mean anomaly, in radians per second.
+    pub m1: f64,
+}
+
+impl Default for EtModel {
+    fn default() -> Self {
+        Self::new(
+            (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
+            NAIF_K,
+            NAIF_EB,
+            NAIF_M0,
+            NAIF_M1,
+        )
+    }
+}
+
+impl EtModel {
+    /// Builds a new model of Ephemeris Time from the `DELTET/DELTA_T_A`, `DELTET/K`, `DELTET/EB` and `DELTET/M` constants of a leap second kernel.
+    pub const fn new(delta_t_a: f64, k: f64, eb: f64, m0: f64, m1: f64) -> Self {
+        Self {
+            delta_t_a,
+            k,
+            eb,
+            m0,
+            m1,
+        }
+    }
+
+    /// Returns ET - TAI in seconds at the provided number of TAI seconds past J2000.
+    pub(crate) fn delta_et_tai(&self, seconds: f64) -> f64 {
+        // Calculate M, the mean anomaly.
+        let m = self.m0 + seconds * self.m1;
+        // Calculate eccentric anomaly
+        let e = m + self.eb * m.sin();
+
+        self.delta_t_a + self.k * e.sin()
+    }
+
+    /// Converts the provided ET duration since J2000 into a TAI duration since J1900.
+    pub(crate) fn et_to_tai(&self, et_duration: Duration) -> Duration {
+        // Run a Newton Raphston to convert find the correct value of the
+        let mut seconds_j2000 = et_duration.to_seconds();
+        for _ in 0..5 {
+            seconds_j2000 += -self.k
+                * (self.m0
+                    + self.m1 * seconds_j2000
+                    + self.eb * (self.m0 + self.m1 * seconds_j2000).sin())
+                .sin();
+        }
+
+        // At this point, we have a good estimate of the number of seconds of this epoch.
+        // Reverse the algorithm:
+        let delta_et_tai = self.delta_et_tai(seconds_j2000 - self.delta_t_a);
+
+        // Match SPICE by changing the UTC definition.
+        et_duration - delta_et_tai.seconds() + TimeScale::ET.prime_epoch_offset()
+    }
+
+    /// Converts the provided TAI duration since J1900 into an ET duration since J2000.
+    pub(crate) fn tai_to_et(&self, tai_duration: Duration) -> Duration {
+        // Run a Newton Raphston to convert find the correct value of the ... ?!
+
+        let mut seconds = (tai_duration - TimeScale::ET.prime_epoch_offset()).to_seconds();
+        for _ in 0..5 {
+            seconds -= -self.k
+                * (self.m0 + self.m1 * seconds + self.eb * (self.m0 + self.m1 * seconds).sin())
+                    .sin();
+        }
+
+        // At this point, we have a good estimate of the number of seconds of this epoch.
+        // Reverse the algorithm:
+        let delta_et_tai = self.delta_et_tai(seconds + self.delta_t_a);
+
+        // Match SPICE by changing the UTC definition.
+        tai_duration + delta_et_tai.seconds() - TimeScale::ET.prime_epoch_offset()
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns the Ephemeris Time (ET) as a Duration since J2000, computed with the provided ET model, e.g. that of a leap second kernel.
+    ///
+    /// The default model returns the same value as [Epoch::to_et_duration].
+    pub fn to_et_duration_with_model(&self, model: EtModel) -> Duration {
+        model.tai_to_et(self.to_tai_duration())
+    }
+
+    #[must_use]
+    /// Returns the Ephemeris Time (ET) as seconds since J2000, computed with the provided ET model.
+    pub fn to_et_seconds_with_model(&self, model: EtModel) -> f64 {
+        self.to_et_duration_with_model(model).to_seconds()
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the Ephemeris Time (ET) duration since J2000, computed with the provided ET model.
+    ///
+    /// The returned Epoch is in TAI: an Epoch in [TimeScale::ET] would otherwise be converted with the default model.
+    pub fn from_et_duration_with_model(duration_since_j2000: Duration, model: EtModel) -> Self {
+        Self::from_tai_duration(model.et_to_tai(duration_since_j2000))
+    }
+}
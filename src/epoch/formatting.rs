@@ -37,8 +37,7 @@ impl fmt::Debug for Epoch {
     /// The default format of an epoch is in UTC
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let ts = TimeScale::UTC;
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_in_time_scale(ts), ts);
+        let (y, mm, dd, hh, min, s, nanos) = self.compute_gregorian_in(ts);
         if nanos == 0 {
             write!(
                 f,
@@ -140,12 +140,28 @@ impl Epoch {
         )
     }
 
+    /// Returns the Gregorian representation of this epoch in the provided time scale, where the instants within a leap second are shown as
+    /// the 61st second of the last minute of the day in UTC, e.g. `2016-12-31T23:59:60.5 UTC`.
+    pub(crate) fn compute_gregorian_in(
+        &self,
+        time_scale: TimeScale,
+    ) -> (i32, u8, u8, u8, u8, u8, u32) {
+        let duration = self.to_duration_in_time_scale(time_scale);
+        if time_scale == TimeScale::UTC && self.is_within_leap_second() {
+            // The UTC reading of the leap second is that of the following second.
+            let (y, mm, dd, hh, min, s, nanos) =
+                Self::compute_gregorian(duration - Unit::Second, time_scale);
+            (y, mm, dd, hh, min, s + 1, nanos)
+        } else {
+            Self::compute_gregorian(duration, time_scale)
+        }
+    }
+
     #[cfg(feature = "std")]
     #[must_use]
     /// Converts the Epoch to Gregorian in the provided time scale and in the ISO8601 format with the time scale appended to the string
     pub fn to_gregorian_str(&self, time_scale: TimeScale) -> String {
-        let (y, mm, dd, hh, min, s, nanos) =
-            Self::compute_gregorian(self.to_duration_in_time_scale(time_scale), time_scale);
+        let (y, mm, dd, hh, min, s, nanos) = self.compute_gregorian_in(time_scale);
 
         if nanos == 0 {
             format!(
@@ -164,6 +180,8 @@ impl Epoch {
     /// Converts the Epoch to the Gregorian UTC equivalent as (year, month, day, hour, minute, second).
     /// WARNING: Nanoseconds are lost in this conversion!
     ///
+    /// The second is 60 if this epoch is within a leap second, cf. [Epoch::is_within_leap_second].
+    ///
     /// # Example
     /// ```
     /// use hifitime::Epoch;
@@ -190,8 +208,7 @@ impl Epoch {
     /// }
     /// ```
     pub fn to_gregorian_utc(&self) -> (i32, u8, u8, u8, u8, u8, u32) {
-        let ts = TimeScale::UTC;
-        Self::compute_gregorian(self.to_duration_in_time_scale(ts), ts)
+        self.compute_gregorian_in(TimeScale::UTC)
     }
 
     #[must_use]
@@ -241,6 +258,11 @@ impl Epoch {
     ///
     /// Note:
     /// The month is ONE indexed, i.e. January is month 1 and December is month 12.
+    ///
+    /// # Leap seconds
+    /// An Epoch in UTC stores the reading of UTC, which cannot represent the leap second itself since its reading is that of the following second.
+    /// Hence, the 61st second of a leap second day in UTC (e.g. `2016-12-31T23:59:60.5`) returns that instant in TAI, which is shown
+    /// as `23:59:60` in UTC by [Epoch::to_gregorian_utc], [Epoch::to_gregorian_str] and the formatter of [Formatter::to_time_scale](crate::efmt::Formatter::to_time_scale).
     #[allow(clippy::too_many_arguments)]
     pub fn maybe_from_gregorian(
         year: i32,
@@ -318,6 +340,16 @@ impl Epoch {
         // Account for this time scale's Gregorian offset.
         duration_wrt_ref -= time_scale.gregorian_epoch_offset();
 
+        if second == 60 && time_scale == TimeScale::UTC {
+            // The UTC reading of the leap second is that of the following second, so return this instant in TAI,
+            // one second after the last second of the day.
+            let last_second = Self {
+                duration: duration_wrt_ref,
+                time_scale,
+            };
+            return Ok(last_second.to_time_scale(TimeScale::TAI) + Unit::Second);
+        }
+
         Ok(Self {
             duration: duration_wrt_ref,
             time_scale,
@@ -0,0 +1,215 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::{fs::File, io::Read, path::Path};
+
+use core::ops::Index;
+
+use crate::text_kernel::{KernelValue, TextKernel};
+use crate::{
+    leap_seconds::{check_sorted, LeapSecond, LeapSecondTable, LookupCache},
+    Epoch, EtModel, HifitimeError, ParsingError, TimeScale,
+};
+
+/// Abbreviated month names of the dates of a leap second kernel.
+pub(super) const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+#[derive(Clone, Debug, Default)]
+/// A leap second provider that uses a NAIF leap second kernel (LSK), e.g. `naif0012.tls`.
+///
+/// The kernel also defines the constants of the NAIF model of Ephemeris Time, cf. [LeapSecondKernel::et_model].
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::LeapSecondKernel;
+/// use hifitime::{Epoch, EtModel};
+///
+/// let lsk = LeapSecondKernel::from_path("naif0012.txt").unwrap();
+/// assert_eq!(lsk.et_model(), EtModel::default());
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+/// assert_eq!(epoch.leap_seconds_with(true, lsk), Some(37.0));
+/// ```
+pub struct LeapSecondKernel {
+    data: Vec<LeapSecond>,
+    iter_pos: usize,
+    et_model: EtModel,
+    cache: LookupCache,
+}
+
+impl LeapSecondKernel {
+    /// Builds a leap second provider from the provided NAIF leap second kernel.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening leap second kernel",
+                })
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading leap second kernel",
+            });
+        }
+
+        Self::from_kernel_str(&contents)
+    }
+
+    /// Builds a leap second provider from the contents of a NAIF leap second kernel.
+    ///
+    /// The `DELTET/DELTA_AT` variable lists pairs of ΔAT and the UTC date from which it applies, e.g. `37, @2017-JAN-1`,
+    /// and the `DELTET/DELTA_T_A`, `DELTET/K`, `DELTET/EB` and `DELTET/M` variables define the ET model.
+    pub fn from_kernel_str(contents: &str) -> Result<Self, HifitimeError> {
+        let kernel = TextKernel::parse(contents)?;
+
+        let single = |name: &str, details: &'static str| -> Result<f64, HifitimeError> {
+            match kernel.numbers(name, details)?.as_slice() {
+                [value] => Ok(*value),
+                _ => Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details,
+                }),
+            }
+        };
+
+        let delta_t_a = single(
+            "DELTET/DELTA_T_A",
+            "DELTET/DELTA_T_A must be a single number",
+        )?;
+        let k = single("DELTET/K", "DELTET/K must be a single number")?;
+        let eb = single("DELTET/EB", "DELTET/EB must be a single number")?;
+        let (m0, m1) = match kernel
+            .numbers("DELTET/M", "DELTET/M must be two numbers")?
+            .as_slice()
+        {
+            [m0, m1] => (*m0, *m1),
+            _ => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details: "DELTET/M must be two numbers",
+                })
+            }
+        };
+
+        let delta_at = kernel.get("DELTET/DELTA_AT").ok_or(HifitimeError::Parse {
+            source: ParsingError::NothingToParse,
+            details: "leap second kernel has no DELTET/DELTA_AT",
+        })?;
+        if delta_at.len() % 2 != 0 {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details: "DELTET/DELTA_AT must be pairs of ΔAT and date",
+            });
+        }
+
+        let mut data = Vec::with_capacity(delta_at.len() / 2);
+        for pair in delta_at.chunks_exact(2) {
+            match pair {
+                [KernelValue::Number(delta_at), KernelValue::Date(date)] => {
+                    let timestamp_tai_s = Self::parse_date(date)?.duration.to_seconds();
+                    data.push(LeapSecond::new(timestamp_tai_s, *delta_at, true));
+                }
+                _ => {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "DELTET/DELTA_AT must be pairs of ΔAT and date",
+                    })
+                }
+            }
+        }
+
+        check_sorted(&data)?;
+
+        Ok(Self {
+            data,
+            iter_pos: 0,
+            et_model: EtModel::new(delta_t_a, k, eb, m0, m1),
+            cache: LookupCache::new(),
+        })
+    }
+
+    /// Returns the model of Ephemeris Time defined by this kernel, to be used with [Epoch::to_et_duration_with_model] and [Epoch::from_et_duration_with_model].
+    pub fn et_model(&self) -> EtModel {
+        self.et_model
+    }
+
+    /// Parses a date of a leap second kernel, e.g. `1972-JAN-1`, at midnight in the same convention as [LeapSecond::timestamp_tai_s].
+    fn parse_date(date: &str) -> Result<Epoch, HifitimeError> {
+        let err = || HifitimeError::Parse {
+            source: ParsingError::ValueError,
+            details: "leap second kernel dates must be formatted as YYYY-MON-DD",
+        };
+        let mut parts = date.split('-');
+        let (year, month, day) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day), None) => (year, month, day),
+            _ => return Err(err()),
+        };
+        let year: i32 = lexical_core::parse(year.as_bytes()).map_err(|_| err())?;
+        let month = match MONTHS
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(month))
+        {
+            Some(idx) => idx as u8 + 1,
+            None => return Err(err()),
+        };
+        let day: u8 = lexical_core::parse(day.as_bytes()).map_err(|_| err())?;
+
+        Epoch::maybe_from_gregorian(year, month, day, 0, 0, 0, 0, TimeScale::TAI).map_err(|_| err())
+    }
+}
+
+impl Iterator for LeapSecondKernel {
+    type Item = LeapSecond;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter_pos += 1;
+        self.data.get(self.iter_pos - 1).copied()
+    }
+}
+
+impl DoubleEndedIterator for LeapSecondKernel {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.iter_pos == self.data.len() {
+            None
+        } else {
+            self.iter_pos += 1;
+            self.data.get(self.data.len() - self.iter_pos).copied()
+        }
+    }
+}
+
+impl Index<usize> for LeapSecondKernel {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl LeapSecondTable for LeapSecondKernel {
+    fn as_slice(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        Some(&self.cache)
+    }
+}
+
+#[allow(deprecated)]
+impl crate::leap_seconds::LeapSecondProvider for LeapSecondKernel {}
@@ -12,12 +12,246 @@
 use pyo3::prelude::*;
 
 #[cfg(feature = "std")]
-pub use super::leap_seconds_file::LeapSecondsFile;
+pub use super::leap_second_kernel::LeapSecondKernel;
+#[cfg(feature = "std")]
+pub use super::leap_seconds_file::{LeapSecondsFile, LeapSecondsFormat};
+pub use super::leap_seconds_list::{leap_seconds_list_len, LeapSecondsList};
 
 use core::ops::Index;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "std")]
+use core::sync::atomic::AtomicBool;
+#[cfg(feature = "std")]
+use std::sync::{Arc, PoisonError, RwLock};
+
+#[cfg(feature = "std")]
+use crate::{Epoch, EtModel};
+
+use crate::{Duration, HifitimeError, ParsingError, Unit, MJD_J1900, SECONDS_PER_DAY};
+
+/// A table of leap seconds, which borrows them as a slice sorted by date such that they are looked up with a binary search.
+///
+/// The built-in providers also remain iterators over their leap seconds, and can be indexed.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::{LeapSecond, LeapSecondTable, LeapSecondSlice};
+/// use hifitime::Epoch;
+///
+/// struct Since2017 {
+///     data: Vec<LeapSecond>,
+/// }
+///
+/// impl LeapSecondTable for Since2017 {
+///     fn as_slice(&self) -> &[LeapSecond] {
+///         &self.data
+///     }
+/// }
+///
+/// let provider = Since2017 {
+///     data: LeapSecondSlice::latest().as_slice()[41..].to_vec(),
+/// };
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+/// assert_eq!(epoch.leap_seconds_with(true, &provider), Some(37.0));
+/// assert_eq!(epoch.leap_seconds_with(true, provider), Some(37.0));
+/// ```
+pub trait LeapSecondTable {
+    /// Returns the leap seconds of this table, which must be sorted by date.
+    fn as_slice(&self) -> &[LeapSecond];
+
+    /// Returns the cache of the previous lookup in this table, if any, such that consecutive lookups between the same leap seconds skip the binary search.
+    ///
+    /// Tables without a cache, which is the default, are always binary searched.
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        None
+    }
+
+    /// Exports these leap seconds in the IETF `leap-seconds.list` format, with the provided last update (`#$` line) and expiration (`#@` line),
+    /// and the SHA-1 hash of the data (`#h` line), cf. [LeapSecondsFile::from_leap_seconds_list_str].
+    ///
+    /// Only the leap seconds announced by the IERS are exported, since this format does not support the drift of UTC before 1972.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondTable, LeapSecondsFile};
+    /// use hifitime::Epoch;
+    ///
+    /// let contents = LatestLeapSeconds::default().to_leap_seconds_list(
+    ///     Epoch::from_gregorian_utc_at_midnight(2024, 7, 8),
+    ///     Epoch::from_gregorian_utc_at_midnight(2025, 6, 28),
+    /// );
+    /// assert!(contents.contains("3692217600\t37\t# 1 Jan 2017"));
+    /// assert_eq!(LeapSecondsFile::from_file_str(&contents).unwrap().count(), 28);
+    /// ```
+    #[cfg(feature = "std")]
+    fn to_leap_seconds_list(&self, last_update: Epoch, expiration: Epoch) -> String {
+        super::leap_seconds_export::to_leap_seconds_list(
+            self.as_slice().iter().copied(),
+            last_update,
+            expiration,
+        )
+    }
+
+    /// Exports these leap seconds as a NAIF leap second kernel, whose `DELTET/DELTA_AT` variable lists the leap seconds announced by the IERS
+    /// and whose other `DELTET` variables are the constants of the provided ET model, cf. [LeapSecondKernel::from_kernel_str].
+    #[cfg(feature = "std")]
+    fn to_naif_lsk(&self, et_model: EtModel) -> String {
+        super::leap_seconds_export::to_naif_lsk(self.as_slice().iter().copied(), et_model)
+    }
+
+    /// Exports these leap seconds in the USNO `tai-utc.dat` format, including the drift of UTC before 1972, cf. [LeapSecondsFile::from_tai_utc_dat_str].
+    ///
+    /// ΔAT is written with seven decimals, and the leap seconds which do not drift are parsed back as announced by the IERS.
+    #[cfg(feature = "std")]
+    fn to_tai_utc_dat(&self) -> String {
+        super::leap_seconds_export::to_tai_utc_dat(self.as_slice().iter().copied())
+    }
+
+    /// Exports these leap seconds as a CSV table, whose header row lists the fields of [LeapSecond].
+    #[cfg(feature = "std")]
+    fn to_csv(&self) -> String {
+        super::leap_seconds_export::to_csv(self.as_slice().iter().copied())
+    }
 
+    /// Exports these leap seconds as a JSON array of objects whose keys are the fields of [LeapSecond].
+    #[cfg(feature = "std")]
+    fn to_json(&self) -> String {
+        super::leap_seconds_export::to_json(self.as_slice().iter().copied())
+    }
+}
+
+impl<L: LeapSecondTable + ?Sized> LeapSecondTable for &L {
+    fn as_slice(&self) -> &[LeapSecond] {
+        (**self).as_slice()
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        (**self).lookup_cache()
+    }
+}
+
+/// The iterator based leap second provider of hifitime 3, whose leap seconds are iterated over in order by date.
+///
+/// Every conversion now borrows the leap seconds of a [LeapSecondTable] instead, and the built-in providers implement both traits.
+/// A provider implementing only this trait can be used with any conversion once wrapped in a [LeapSecondIterAdapter] (requires the `std` feature).
+#[deprecated(
+    since = "4.0.0",
+    note = "implement `LeapSecondTable` instead, or wrap this provider in a `LeapSecondIterAdapter`"
+)]
 pub trait LeapSecondProvider: DoubleEndedIterator<Item = LeapSecond> + Index<usize> {}
 
+/// Adapts a provider of the deprecated [LeapSecondProvider] trait into a [LeapSecondTable] by collecting its leap seconds.
+///
+/// # Example
+/// ```
+/// #![allow(deprecated)]
+/// use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondIterAdapter};
+/// use hifitime::Epoch;
+///
+/// let adapter = LeapSecondIterAdapter::new(LatestLeapSeconds::default()).unwrap();
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+/// assert_eq!(epoch.leap_seconds_with(true, &adapter), Some(37.0));
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+pub struct LeapSecondIterAdapter {
+    data: Vec<LeapSecond>,
+    cache: LookupCache,
+}
+
+#[cfg(feature = "std")]
+impl LeapSecondIterAdapter {
+    /// Collects the leap seconds of the provided provider, which must be sorted by date.
+    #[allow(deprecated)]
+    pub fn new<P: LeapSecondProvider>(provider: P) -> Result<Self, HifitimeError> {
+        let data: Vec<LeapSecond> = provider.collect();
+        check_sorted(&data)?;
+        Ok(Self {
+            data,
+            cache: LookupCache::new(),
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl LeapSecondTable for LeapSecondIterAdapter {
+    fn as_slice(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        Some(&self.cache)
+    }
+}
+
+/// Cache of the number of leap seconds started at the previous lookup in a [LeapSecondTable], which is checked before being reused.
+///
+/// It is ignored when comparing tables, and does nothing on targets without atomics.
+#[derive(Debug, Default)]
+pub struct LookupCache {
+    #[cfg(target_has_atomic = "ptr")]
+    count: AtomicUsize,
+}
+
+impl LookupCache {
+    /// Builds an empty cache.
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(target_has_atomic = "ptr")]
+            count: AtomicUsize::new(0),
+        }
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    fn get(&self) -> usize {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    #[cfg(not(target_has_atomic = "ptr"))]
+    fn get(&self) -> usize {
+        0
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    fn set(&self, count: usize) {
+        self.count.store(count, Ordering::Relaxed);
+    }
+
+    #[cfg(not(target_has_atomic = "ptr"))]
+    fn set(&self, _count: usize) {}
+}
+
+impl Clone for LookupCache {
+    fn clone(&self) -> Self {
+        let cache = Self::new();
+        cache.set(self.get());
+        cache
+    }
+}
+
+impl PartialEq for LookupCache {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// Returns an error if the provided leap seconds are not sorted by date.
+pub(crate) const fn check_sorted(data: &[LeapSecond]) -> Result<(), HifitimeError> {
+    let mut i = 1;
+    while i < data.len() {
+        if data[i - 1].timestamp_tai_s >= data[i].timestamp_tai_s {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::ValueError,
+                details: "leap seconds are not sorted by date",
+            });
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
 /// A structure representing a leap second
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 #[repr(C)]
@@ -30,6 +264,10 @@ pub struct LeapSecond {
     pub delta_at: f64,
     /// Whether or not this leap second was announced by the IERS.
     pub announced_by_iers: bool,
+    /// Modified Julian Date in UTC from which ΔAT drifts, only used between 1961 and 1972.
+    drift_ref_mjd: f64,
+    /// Drift of ΔAT in seconds per day after `drift_ref_mjd`, zero since 1972 when UTC stopped drifting with respect to TAI.
+    drift_s_per_day: f64,
 }
 
 impl LeapSecond {
@@ -38,58 +276,97 @@ impl LeapSecond {
             timestamp_tai_s,
             delta_at,
             announced_by_iers: announced,
+            drift_ref_mjd: 0.0,
+            drift_s_per_day: 0.0,
+        }
+    }
+
+    /// Builds an unannounced leap second of the early UTC, when ΔAT was `delta_at + (MJD - drift_ref_mjd) * drift_s_per_day`.
+    pub const fn new_with_drift(
+        timestamp_tai_s: f64,
+        delta_at: f64,
+        drift_ref_mjd: f64,
+        drift_s_per_day: f64,
+    ) -> Self {
+        Self {
+            timestamp_tai_s,
+            delta_at,
+            announced_by_iers: false,
+            drift_ref_mjd,
+            drift_s_per_day,
         }
     }
+
+    /// Returns the Modified Julian Date in UTC from which ΔAT drifts, only used between 1961 and 1972, cf. [LeapSecond::new_with_drift].
+    pub const fn drift_ref_mjd(&self) -> f64 {
+        self.drift_ref_mjd
+    }
+
+    /// Returns the drift of ΔAT in seconds per day, zero since 1972 when UTC stopped drifting with respect to TAI.
+    pub const fn drift_s_per_day(&self) -> f64 {
+        self.drift_s_per_day
+    }
+
+    /// Returns ΔAT on the provided Modified Julian Date in UTC, accounting for the drift of the early UTC.
+    pub fn delta_at_on_mjd_utc(&self, mjd_utc: f64) -> f64 {
+        self.delta_at + (mjd_utc - self.drift_ref_mjd) * self.drift_s_per_day
+    }
+
+    /// Returns ΔAT on the provided Modified Julian Date in TAI, accounting for the drift of the early UTC.
+    pub fn delta_at_on_mjd_tai(&self, mjd_tai: f64) -> f64 {
+        // Solves ΔAT = delta_at + (mjd_tai - ΔAT / 86400 - drift_ref_mjd) * drift_s_per_day
+        self.delta_at_on_mjd_utc(mjd_tai) / (1.0 + self.drift_s_per_day / SECONDS_PER_DAY)
+    }
 }
 
 const LATEST_LEAP_SECONDS: [LeapSecond; 42] = [
-    LeapSecond::new(1_893_369_600.0, 1.417818, false), // SOFA: 01 Jan 1960
-    LeapSecond::new(1_924_992_000.0, 1.422818, false), // SOFA: 01 Jan 1961
-    LeapSecond::new(1_943_308_800.0, 1.372818, false), // SOFA: 01 Aug 1961
-    LeapSecond::new(1_956_528_000.0, 1.845858, false), // SOFA: 01 Jan 1962
-    LeapSecond::new(2_014_329_600.0, 1.945858, false), // SOFA: 01 Jan 1963
-    LeapSecond::new(2_019_600_000.0, 3.24013, false),  // SOFA: 01 Jan 1964
-    LeapSecond::new(2_027_462_400.0, 3.34013, false),  // SOFA: 01 Apr 1964
-    LeapSecond::new(2_040_681_600.0, 3.44013, false),  // SOFA: 01 Sep 1964
-    LeapSecond::new(2_051_222_400.0, 3.54013, false),  // SOFA: 01 Jan 1965
-    LeapSecond::new(2_056_320_000.0, 3.64013, false),  // SOFA: 01 Mar 1965
-    LeapSecond::new(2_066_860_800.0, 3.74013, false),  // SOFA: 01 Jul 1965
-    LeapSecond::new(2_072_217_600.0, 3.84013, false),  // SOFA: 01 Sep 1965
-    LeapSecond::new(2_082_758_400.0, 4.31317, false),  // SOFA: 01 Jan 1966
-    LeapSecond::new(2_148_508_800.0, 4.21317, false),  // SOFA: 01 Feb 1968
-    LeapSecond::new(2_272_060_800.0, 10.0, true),      // IERS: 01 Jan 1972
-    LeapSecond::new(2_287_785_600.0, 11.0, true),      // IERS: 01 Jul 1972
-    LeapSecond::new(2_303_683_200.0, 12.0, true),      // IERS: 01 Jan 1973
-    LeapSecond::new(2_335_219_200.0, 13.0, true),      // IERS: 01 Jan 1974
-    LeapSecond::new(2_366_755_200.0, 14.0, true),      // IERS: 01 Jan 1975
-    LeapSecond::new(2_398_291_200.0, 15.0, true),      // IERS: 01 Jan 1976
-    LeapSecond::new(2_429_913_600.0, 16.0, true),      // IERS: 01 Jan 1977
-    LeapSecond::new(2_461_449_600.0, 17.0, true),      // IERS: 01 Jan 1978
-    LeapSecond::new(2_492_985_600.0, 18.0, true),      // IERS: 01 Jan 1979
-    LeapSecond::new(2_524_521_600.0, 19.0, true),      // IERS: 01 Jan 1980
-    LeapSecond::new(2_571_782_400.0, 20.0, true),      // IERS: 01 Jul 1981
-    LeapSecond::new(2_603_318_400.0, 21.0, true),      // IERS: 01 Jul 1982
-    LeapSecond::new(2_634_854_400.0, 22.0, true),      // IERS: 01 Jul 1983
-    LeapSecond::new(2_698_012_800.0, 23.0, true),      // IERS: 01 Jul 1985
-    LeapSecond::new(2_776_982_400.0, 24.0, true),      // IERS: 01 Jan 1988
-    LeapSecond::new(2_840_140_800.0, 25.0, true),      // IERS: 01 Jan 1990
-    LeapSecond::new(2_871_676_800.0, 26.0, true),      // IERS: 01 Jan 1991
-    LeapSecond::new(2_918_937_600.0, 27.0, true),      // IERS: 01 Jul 1992
-    LeapSecond::new(2_950_473_600.0, 28.0, true),      // IERS: 01 Jul 1993
-    LeapSecond::new(2_982_009_600.0, 29.0, true),      // IERS: 01 Jul 1994
-    LeapSecond::new(3_029_443_200.0, 30.0, true),      // IERS: 01 Jan 1996
-    LeapSecond::new(3_076_704_000.0, 31.0, true),      // IERS: 01 Jul 1997
-    LeapSecond::new(3_124_137_600.0, 32.0, true),      // IERS: 01 Jan 1999
-    LeapSecond::new(3_345_062_400.0, 33.0, true),      // IERS: 01 Jan 2006
-    LeapSecond::new(3_439_756_800.0, 34.0, true),      // IERS: 01 Jan 2009
-    LeapSecond::new(3_550_089_600.0, 35.0, true),      // IERS: 01 Jul 2012
-    LeapSecond::new(3_644_697_600.0, 36.0, true),      // IERS: 01 Jul 2015
-    LeapSecond::new(3_692_217_600.0, 37.0, true),      // IERS: 01 Jan 2017
+    LeapSecond::new_with_drift(1_893_369_600.0, 1.417818, 37_300.0, 0.001_296), // SOFA: 01 Jan 1960
+    LeapSecond::new_with_drift(1_924_992_000.0, 1.422818, 37_300.0, 0.001_296), // SOFA: 01 Jan 1961
+    LeapSecond::new_with_drift(1_943_308_800.0, 1.372818, 37_300.0, 0.001_296), // SOFA: 01 Aug 1961
+    LeapSecond::new_with_drift(1_956_528_000.0, 1.845858, 37_665.0, 0.001_123_2), // SOFA: 01 Jan 1962
+    LeapSecond::new_with_drift(2_014_329_600.0, 1.945858, 37_665.0, 0.001_123_2), // SOFA: 01 Nov 1963
+    LeapSecond::new_with_drift(2_019_600_000.0, 3.24013, 38_761.0, 0.001_296), // SOFA: 01 Jan 1964
+    LeapSecond::new_with_drift(2_027_462_400.0, 3.34013, 38_761.0, 0.001_296), // SOFA: 01 Apr 1964
+    LeapSecond::new_with_drift(2_040_681_600.0, 3.44013, 38_761.0, 0.001_296), // SOFA: 01 Sep 1964
+    LeapSecond::new_with_drift(2_051_222_400.0, 3.54013, 38_761.0, 0.001_296), // SOFA: 01 Jan 1965
+    LeapSecond::new_with_drift(2_056_320_000.0, 3.64013, 38_761.0, 0.001_296), // SOFA: 01 Mar 1965
+    LeapSecond::new_with_drift(2_066_860_800.0, 3.74013, 38_761.0, 0.001_296), // SOFA: 01 Jul 1965
+    LeapSecond::new_with_drift(2_072_217_600.0, 3.84013, 38_761.0, 0.001_296), // SOFA: 01 Sep 1965
+    LeapSecond::new_with_drift(2_082_758_400.0, 4.31317, 39_126.0, 0.002_592), // SOFA: 01 Jan 1966
+    LeapSecond::new_with_drift(2_148_508_800.0, 4.21317, 39_126.0, 0.002_592), // SOFA: 01 Feb 1968
+    LeapSecond::new(2_272_060_800.0, 10.0, true),                              // IERS: 01 Jan 1972
+    LeapSecond::new(2_287_785_600.0, 11.0, true),                              // IERS: 01 Jul 1972
+    LeapSecond::new(2_303_683_200.0, 12.0, true),                              // IERS: 01 Jan 1973
+    LeapSecond::new(2_335_219_200.0, 13.0, true),                              // IERS: 01 Jan 1974
+    LeapSecond::new(2_366_755_200.0, 14.0, true),                              // IERS: 01 Jan 1975
+    LeapSecond::new(2_398_291_200.0, 15.0, true),                              // IERS: 01 Jan 1976
+    LeapSecond::new(2_429_913_600.0, 16.0, true),                              // IERS: 01 Jan 1977
+    LeapSecond::new(2_461_449_600.0, 17.0, true),                              // IERS: 01 Jan 1978
+    LeapSecond::new(2_492_985_600.0, 18.0, true),                              // IERS: 01 Jan 1979
+    LeapSecond::new(2_524_521_600.0, 19.0, true),                              // IERS: 01 Jan 1980
+    LeapSecond::new(2_571_782_400.0, 20.0, true),                              // IERS: 01 Jul 1981
+    LeapSecond::new(2_603_318_400.0, 21.0, true),                              // IERS: 01 Jul 1982
+    LeapSecond::new(2_634_854_400.0, 22.0, true),                              // IERS: 01 Jul 1983
+    LeapSecond::new(2_698_012_800.0, 23.0, true),                              // IERS: 01 Jul 1985
+    LeapSecond::new(2_776_982_400.0, 24.0, true),                              // IERS: 01 Jan 1988
+    LeapSecond::new(2_840_140_800.0, 25.0, true),                              // IERS: 01 Jan 1990
+    LeapSecond::new(2_871_676_800.0, 26.0, true),                              // IERS: 01 Jan 1991
+    LeapSecond::new(2_918_937_600.0, 27.0, true),                              // IERS: 01 Jul 1992
+    LeapSecond::new(2_950_473_600.0, 28.0, true),                              // IERS: 01 Jul 1993
+    LeapSecond::new(2_982_009_600.0, 29.0, true),                              // IERS: 01 Jul 1994
+    LeapSecond::new(3_029_443_200.0, 30.0, true),                              // IERS: 01 Jan 1996
+    LeapSecond::new(3_076_704_000.0, 31.0, true),                              // IERS: 01 Jul 1997
+    LeapSecond::new(3_124_137_600.0, 32.0, true),                              // IERS: 01 Jan 1999
+    LeapSecond::new(3_345_062_400.0, 33.0, true),                              // IERS: 01 Jan 2006
+    LeapSecond::new(3_439_756_800.0, 34.0, true),                              // IERS: 01 Jan 2009
+    LeapSecond::new(3_550_089_600.0, 35.0, true),                              // IERS: 01 Jul 2012
+    LeapSecond::new(3_644_697_600.0, 36.0, true),                              // IERS: 01 Jul 2015
+    LeapSecond::new(3_692_217_600.0, 37.0, true),                              // IERS: 01 Jan 2017
 ];
 
 /// List of leap seconds from https://www.ietf.org/timezones/data/leap-seconds.list .
 /// This list corresponds the number of seconds in TAI to the UTC offset and to whether it was an announced leap second or not.
-/// The unannoucned leap seconds come from dat.c in the SOFA library.
+/// The unannoucned leap seconds come from dat.c in the SOFA library, along with the drift of UTC before 1972.
 #[cfg_attr(feature = "python", pyclass)]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 #[derive(Clone, Debug)]
@@ -148,15 +425,221 @@ impl Index<usize> for LatestLeapSeconds {
     }
 }
 
+impl LeapSecondTable for LatestLeapSeconds {
+    fn as_slice(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        Some(&LATEST_LOOKUP_CACHE)
+    }
+}
+
+#[allow(deprecated)]
 impl LeapSecondProvider for LatestLeapSeconds {}
 
+/// Lookup cache of the built-in leap seconds, shared by [LatestLeapSeconds] and [LeapSecondSlice::latest] since they all have the same leap seconds.
+static LATEST_LOOKUP_CACHE: LookupCache = LookupCache::new();
+
+/// The leap seconds used by all UTC conversions instead of [LatestLeapSeconds], cf. [install_provider].
+#[cfg(feature = "std")]
+static INSTALLED_LEAP_SECONDS: RwLock<Option<Arc<[LeapSecond]>>> = RwLock::new(None);
+
+/// Whether leap seconds are installed, such that UTC conversions only take the lock of [INSTALLED_LEAP_SECONDS] when they are.
+#[cfg(feature = "std")]
+static HAS_INSTALLED_LEAP_SECONDS: AtomicBool = AtomicBool::new(false);
+
+/// Lookup cache of the installed leap seconds.
+#[cfg(feature = "std")]
+static INSTALLED_LOOKUP_CACHE: LookupCache = LookupCache::new();
+
+/// Installs the leap seconds of the provided provider, e.g. a [LeapSecondsFile], as those used by every conversion from or into UTC
+/// (and the time scales derived from it), replacing the previously installed ones.
+/// Until a provider is installed, the built-in [LatestLeapSeconds] are used.
+///
+/// To use a provider for specific conversions only, or without the standard library, use [Epoch::to_time_scale_with_leap_seconds](crate::Epoch::to_time_scale_with_leap_seconds).
+///
+/// Returns an error, without installing anything, if the leap seconds of the provider are not sorted by date.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::{install_provider, uninstall_provider, LatestLeapSeconds};
+/// use hifitime::{Epoch, TimeScale};
+///
+/// install_provider(LatestLeapSeconds::default()).unwrap();
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2017, 1, 1);
+/// assert_eq!(epoch.leap_seconds(true), Some(37.0));
+/// assert_eq!(uninstall_provider().map(|data| data.len()), Some(42));
+/// ```
+#[cfg(feature = "std")]
+pub fn install_provider<L: LeapSecondTable>(provider: L) -> Result<(), HifitimeError> {
+    check_sorted(provider.as_slice())?;
+    let data: Arc<[LeapSecond]> = Arc::from(provider.as_slice());
+    let mut installed = INSTALLED_LEAP_SECONDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    *installed = Some(data);
+    INSTALLED_LOOKUP_CACHE.set(0);
+    HAS_INSTALLED_LEAP_SECONDS.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Removes and returns the installed leap seconds, if any. UTC conversions then use the built-in [LatestLeapSeconds].
+#[cfg(feature = "std")]
+pub fn uninstall_provider() -> Option<Vec<LeapSecond>> {
+    let mut installed = INSTALLED_LEAP_SECONDS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    HAS_INSTALLED_LEAP_SECONDS.store(false, Ordering::Release);
+    installed.take().map(|data| data.to_vec())
+}
+
+/// Returns the installed leap seconds, if any, without holding the lock during the conversion.
+/// Without any installed leap seconds, which is the common case, this does not take the lock.
+#[cfg(feature = "std")]
+pub(crate) fn installed_leap_seconds() -> Option<Arc<[LeapSecond]>> {
+    if !HAS_INSTALLED_LEAP_SECONDS.load(Ordering::Acquire) {
+        return None;
+    }
+    INSTALLED_LEAP_SECONDS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+/// A leap second provider borrowing leap seconds sorted by date, e.g. those of another provider, without copying them.
+#[derive(Copy, Clone, Debug)]
+pub struct LeapSecondSlice<'a> {
+    data: &'a [LeapSecond],
+    cache: Option<&'a LookupCache>,
+}
+
+impl<'a> LeapSecondSlice<'a> {
+    /// Borrows the provided leap seconds, or returns an error if they are not sorted by date.
+    pub const fn new(data: &'a [LeapSecond]) -> Result<Self, HifitimeError> {
+        match check_sorted(data) {
+            Ok(()) => Ok(Self { data, cache: None }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Borrows the provided leap seconds like [LeapSecondSlice::new], and the provided cache for their lookups.
+    pub const fn with_cache(
+        data: &'a [LeapSecond],
+        cache: &'a LookupCache,
+    ) -> Result<Self, HifitimeError> {
+        match check_sorted(data) {
+            Ok(()) => Ok(Self {
+                data,
+                cache: Some(cache),
+            }),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Borrows the installed leap seconds, which were sorted when installed.
+    #[cfg(feature = "std")]
+    pub(crate) fn installed(data: &'a [LeapSecond]) -> Self {
+        Self {
+            data,
+            cache: Some(&INSTALLED_LOOKUP_CACHE),
+        }
+    }
+}
+
+impl LeapSecondSlice<'static> {
+    /// Borrows the built-in leap seconds of [LatestLeapSeconds].
+    pub const fn latest() -> Self {
+        Self {
+            data: &LATEST_LEAP_SECONDS,
+            cache: Some(&LATEST_LOOKUP_CACHE),
+        }
+    }
+}
+
+impl PartialEq for LeapSecondSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl Index<usize> for LeapSecondSlice<'_> {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl LeapSecondTable for LeapSecondSlice<'_> {
+    fn as_slice(&self) -> &[LeapSecond] {
+        self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        self.cache
+    }
+}
+
+/// Returns the leap second in effect at the provided duration past J1900, which is the reading of a UTC epoch if `utc` is set, and a TAI duration otherwise.
+///
+/// The leap seconds started at that duration are counted with a binary search, unless this count is the same as the previous lookup in that table,
+/// which is the case of most conversions since leap seconds are months apart.
+pub(crate) fn find_leap_second<L: LeapSecondTable + ?Sized>(
+    table: &L,
+    duration: Duration,
+    utc: bool,
+    iers_only: bool,
+) -> Option<&LeapSecond> {
+    // The timestamps of the leap seconds are the readings of the UTC midnights from which they apply,
+    // and in TAI, the new offset only applies at the end of the leap second, when that UTC midnight is reached.
+    let start = |leap_second: &LeapSecond| {
+        let midnight = leap_second.timestamp_tai_s * Unit::Second;
+        if utc {
+            midnight
+        } else {
+            midnight
+                + leap_second
+                    .delta_at_on_mjd_utc(leap_second.timestamp_tai_s / SECONDS_PER_DAY + MJD_J1900)
+                    * Unit::Second
+        }
+    };
+
+    let leap_seconds = table.as_slice();
+    let cache = table.lookup_cache();
+    let hint = cache.map_or(0, LookupCache::get);
+    let count = if hint <= leap_seconds.len()
+        && (hint == 0 || start(&leap_seconds[hint - 1]) <= duration)
+        && (hint == leap_seconds.len() || start(&leap_seconds[hint]) > duration)
+    {
+        hint
+    } else {
+        let count = leap_seconds.partition_point(|leap_second| start(leap_second) <= duration);
+        if let Some(cache) = cache {
+            cache.set(count);
+        }
+        count
+    };
+
+    let is_used = |leap_second: &&LeapSecond| !iers_only || leap_second.announced_by_iers;
+    match leap_seconds[..count].iter().rev().find(is_used) {
+        Some(leap_second) => Some(leap_second),
+        // The first offset applies from its UTC midnight in TAI as well, since UTC is not defined before it.
+        None if !utc => leap_seconds
+            .iter()
+            .find(is_used)
+            .filter(|leap_second| leap_second.timestamp_tai_s * Unit::Second <= duration),
+        None => None,
+    }
+}
+
 #[test]
 fn leap_second_fetch() {
     let leap_seconds = LatestLeapSeconds::default();
 
     assert_eq!(
         leap_seconds[0],
-        LeapSecond::new(1_893_369_600.0, 1.417818, false),
+        LeapSecond::new_with_drift(1_893_369_600.0, 1.417818, 37_300.0, 0.001_296),
     );
     assert_eq!(
         leap_seconds[41],
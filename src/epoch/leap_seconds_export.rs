@@ -0,0 +1,195 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Writers of leap second tables, cf. [LeapSecondTable](crate::leap_seconds::LeapSecondTable).
+
+use std::fmt::Write;
+
+use super::leap_second_kernel::MONTHS;
+use super::sha1::sha1;
+use crate::leap_seconds::LeapSecond;
+use crate::{Epoch, EtModel, MJD_J1900, MJD_OFFSET, SECONDS_PER_DAY};
+
+/// MJD from which ΔAT no longer drifts, written as the reference MJD of the `tai-utc.dat` lines since 1972.
+const TAI_UTC_DAT_MJD_1972: f64 = 41_317.0;
+
+/// Returns the date of the provided leap second as (year, month, day).
+fn date_of(leap_second: &LeapSecond) -> (i32, u8, u8) {
+    // The timestamp is the reading of the UTC midnight from which the leap second applies.
+    let (year, month, day, _, _, _, _) =
+        Epoch::from_tai_seconds(leap_second.timestamp_tai_s).to_gregorian_tai();
+    (year, month, day)
+}
+
+/// Formats a number as a NAIF text kernel double precision number, e.g. `1.657D-3`.
+fn naif_number(value: f64) -> String {
+    format!("{value:E}").replace('E', "D")
+}
+
+pub(crate) fn to_leap_seconds_list<I: Iterator<Item = LeapSecond>>(
+    leap_seconds: I,
+    last_update: Epoch,
+    expiration: Epoch,
+) -> String {
+    let last_update_ntp = last_update.to_utc_duration().to_seconds() as u64;
+    let expiration_ntp = expiration.to_utc_duration().to_seconds() as u64;
+
+    let mut contents = String::new();
+    let mut hashed_data = format!("{last_update_ntp}{expiration_ntp}");
+    writeln!(contents, "#").unwrap();
+    writeln!(contents, "#\tLeap seconds exported by hifitime.").unwrap();
+    writeln!(
+        contents,
+        "#\tEach line lists the NTP timestamp (seconds since 1900-01-01T00:00:00 UTC) from which TAI - UTC is the value of the second column."
+    )
+    .unwrap();
+    writeln!(contents, "#").unwrap();
+    writeln!(contents, "#$\t{last_update_ntp}").unwrap();
+    writeln!(contents, "#@\t{expiration_ntp}").unwrap();
+    writeln!(contents, "#").unwrap();
+
+    for leap_second in leap_seconds.filter(|leap_second| leap_second.announced_by_iers) {
+        let timestamp = leap_second.timestamp_tai_s as u64;
+        let delta_at = leap_second.delta_at as u8;
+        let (year, month, day) = date_of(&leap_second);
+        let month_name = MONTHS[usize::from(month) - 1];
+        writeln!(
+            contents,
+            "{timestamp}\t{delta_at}\t# {day} {}{} {year}",
+            &month_name[..1],
+            month_name[1..].to_lowercase()
+        )
+        .unwrap();
+        write!(hashed_data, "{timestamp}{delta_at}").unwrap();
+    }
+
+    let hash = sha1(hashed_data.as_bytes())
+        .iter()
+        .map(|word| format!("{word:x}"))
+        .collect::<Vec<String>>()
+        .join(" ");
+    writeln!(contents, "#").unwrap();
+    writeln!(contents, "#h\t{hash}").unwrap();
+
+    contents
+}
+
+pub(crate) fn to_naif_lsk<I: Iterator<Item = LeapSecond>>(
+    leap_seconds: I,
+    et_model: EtModel,
+) -> String {
+    let mut contents = String::new();
+    writeln!(contents, "KPL/LSK").unwrap();
+    writeln!(contents).unwrap();
+    writeln!(contents, "Leap seconds exported by hifitime.").unwrap();
+    writeln!(contents).unwrap();
+    writeln!(contents, "\\begindata").unwrap();
+    writeln!(contents).unwrap();
+    writeln!(
+        contents,
+        "DELTET/DELTA_T_A       =   {}",
+        naif_number(et_model.delta_t_a)
+    )
+    .unwrap();
+    writeln!(
+        contents,
+        "DELTET/K               =   {}",
+        naif_number(et_model.k)
+    )
+    .unwrap();
+    writeln!(
+        contents,
+        "DELTET/EB              =   {}",
+        naif_number(et_model.eb)
+    )
+    .unwrap();
+    writeln!(
+        contents,
+        "DELTET/M               = ( {} {} )",
+        naif_number(et_model.m0),
+        naif_number(et_model.m1)
+    )
+    .unwrap();
+    writeln!(contents).unwrap();
+
+    let mut prefix = "DELTET/DELTA_AT        = (";
+    for leap_second in leap_seconds.filter(|leap_second| leap_second.announced_by_iers) {
+        let (year, month, day) = date_of(&leap_second);
+        writeln!(
+            contents,
+            "{prefix} {},   @{year}-{}-{day}",
+            leap_second.delta_at,
+            MONTHS[usize::from(month) - 1]
+        )
+        .unwrap();
+        prefix = "                          ";
+    }
+    writeln!(contents, "                           )").unwrap();
+    writeln!(contents).unwrap();
+    writeln!(contents, "\\begintext").unwrap();
+
+    contents
+}
+
+pub(crate) fn to_tai_utc_dat<I: Iterator<Item = LeapSecond>>(leap_seconds: I) -> String {
+    let mut contents = String::new();
+    for leap_second in leap_seconds {
+        let (year, month, day) = date_of(&leap_second);
+        let jd = leap_second.timestamp_tai_s / SECONDS_PER_DAY + MJD_J1900 + MJD_OFFSET;
+        let drift_ref_mjd = if leap_second.drift_s_per_day() == 0.0 {
+            TAI_UTC_DAT_MJD_1972
+        } else {
+            leap_second.drift_ref_mjd()
+        };
+        writeln!(
+            contents,
+            " {year:>4} {} {day:>2} =JD {jd:.1}  TAI-UTC= {:>12.7} S + (MJD - {drift_ref_mjd:.0}.) X {:?} S",
+            MONTHS[usize::from(month) - 1],
+            leap_second.delta_at,
+            leap_second.drift_s_per_day()
+        )
+        .unwrap();
+    }
+    contents
+}
+
+pub(crate) fn to_csv<I: Iterator<Item = LeapSecond>>(leap_seconds: I) -> String {
+    let mut contents =
+        String::from("timestamp_tai_s,delta_at,announced_by_iers,drift_ref_mjd,drift_s_per_day\n");
+    for leap_second in leap_seconds {
+        writeln!(
+            contents,
+            "{},{},{},{},{}",
+            leap_second.timestamp_tai_s,
+            leap_second.delta_at,
+            leap_second.announced_by_iers,
+            leap_second.drift_ref_mjd(),
+            leap_second.drift_s_per_day()
+        )
+        .unwrap();
+    }
+    contents
+}
+
+pub(crate) fn to_json<I: Iterator<Item = LeapSecond>>(leap_seconds: I) -> String {
+    let entries = leap_seconds
+        .map(|leap_second| {
+            format!(
+                "  {{\"timestamp_tai_s\": {}, \"delta_at\": {}, \"announced_by_iers\": {}, \"drift_ref_mjd\": {}, \"drift_s_per_day\": {}}}",
+                leap_second.timestamp_tai_s,
+                leap_second.delta_at,
+                leap_second.announced_by_iers,
+                leap_second.drift_ref_mjd(),
+                leap_second.drift_s_per_day()
+            )
+        })
+        .collect::<Vec<String>>();
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
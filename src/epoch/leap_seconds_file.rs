@@ -15,11 +15,63 @@ use std::{fs::File, io::Read, path::Path};
 
 use core::ops::Index;
 
+use core::str::FromStr;
+
+use super::sha1::sha1;
 use crate::{
-    leap_seconds::{LeapSecond, LeapSecondProvider},
-    HifitimeError, ParsingError,
+    leap_seconds::{LeapSecond, LeapSecondTable, LookupCache},
+    Epoch, HifitimeError, LineContent, MonthName, ParsingError, TimeScale, Unit, MJD_J1900,
+    MJD_OFFSET, SECONDS_PER_DAY,
 };
 
+/// A format of leap seconds file, cf. [LeapSecondsFile::from_str_with_format].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LeapSecondsFormat {
+    /// IETF `leap-seconds.list`, cf. [LeapSecondsFile::from_leap_seconds_list_str]
+    LeapSecondsList,
+    /// USNO `tai-utc.dat`, cf. [LeapSecondsFile::from_tai_utc_dat_str]
+    TaiUtcDat,
+    /// IERS `Leap_Second.dat`, cf. [LeapSecondsFile::from_leap_second_dat_str]
+    LeapSecondDat,
+}
+
+impl LeapSecondsFormat {
+    /// Detects the format of the provided contents of a leap seconds file from its markers:
+    ///
+    /// + the `TAI-UTC=` of the lines of a USNO `tai-utc.dat`;
+    /// + the special comment lines (`#$`, `#@` and `#h`) of an IETF `leap-seconds.list`;
+    /// + the `File expires on` or `TAI-UTC (s)` header of an IERS `Leap_Second.dat`.
+    ///
+    /// Without any of these, e.g. for an excerpt without its header, the first data line must either start with an integer NTP timestamp (`leap-seconds.list`)
+    /// or with an MJD with a decimal point (`Leap_Second.dat`). Returns None if the format cannot be detected.
+    pub fn detect(contents: &str) -> Option<Self> {
+        for line in contents.lines() {
+            if line.contains("TAI-UTC=") {
+                return Some(Self::TaiUtcDat);
+            } else if line.starts_with("#$") || line.starts_with("#@") || line.starts_with("#h") {
+                return Some(Self::LeapSecondsList);
+            } else if line.contains("File expires on") || line.contains("TAI-UTC (s)") {
+                return Some(Self::LeapSecondDat);
+            }
+        }
+
+        let first_token = contents
+            .lines()
+            .find(|line| !line.trim().is_empty() && !line.starts_with('#'))?
+            .split_whitespace()
+            .next()?;
+        if first_token.bytes().all(|byte| byte.is_ascii_digit()) {
+            Some(Self::LeapSecondsList)
+        } else if first_token.contains('.')
+            && lexical_core::parse::<f64>(first_token.as_bytes()).is_ok()
+        {
+            Some(Self::LeapSecondDat)
+        } else {
+            None
+        }
+    }
+}
+
 #[repr(C)]
 #[cfg_attr(feature = "python", pyclass)]
 #[derive(Clone, Debug, Default)]
@@ -27,11 +79,27 @@ use crate::{
 pub struct LeapSecondsFile {
     data: Vec<LeapSecond>,
     iter_pos: usize,
+    last_update: Option<Epoch>,
+    expiration: Option<Epoch>,
+    cache: LookupCache,
 }
 
 impl LeapSecondsFile {
-    /// Builds a leap second provider from the provided Leap Seconds file in IERS format as found on <https://www.ietf.org/timezones/data/leap-seconds.list> .
+    /// Builds a leap second provider from the provided leap seconds file, whose format is detected automatically, cf. [LeapSecondsFile::from_file_str].
     pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        Self::from_file_str(&Self::read(path)?)
+    }
+
+    /// Builds a leap second provider from the provided leap seconds file in the provided format.
+    pub fn from_path_with_format<P: AsRef<Path>>(
+        path: P,
+        format: LeapSecondsFormat,
+    ) -> Result<Self, HifitimeError> {
+        Self::from_str_with_format(&Self::read(path)?, format)
+    }
+
+    /// Reads the provided leap seconds file.
+    fn read<P: AsRef<Path>>(path: P) -> Result<String, HifitimeError> {
         let mut f = match File::open(path) {
             Ok(f) => f,
             Err(e) => {
@@ -50,53 +118,374 @@ impl LeapSecondsFile {
             });
         }
 
+        Ok(contents)
+    }
+
+    /// Builds a leap second provider from the contents of a leap seconds file in any of the supported formats:
+    ///
+    /// + the IETF `leap-seconds.list`, cf. [LeapSecondsFile::from_leap_seconds_list_str];
+    /// + the USNO `tai-utc.dat`, cf. [LeapSecondsFile::from_tai_utc_dat_str];
+    /// + the IERS `Leap_Second.dat`, cf. [LeapSecondsFile::from_leap_second_dat_str].
+    ///
+    /// The format is detected from the markers of each format, cf. [LeapSecondsFormat::detect]. Use [LeapSecondsFile::from_str_with_format] to specify it instead.
+    pub fn from_file_str(contents: &str) -> Result<Self, HifitimeError> {
+        if !contents
+            .lines()
+            .any(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::NothingToParse,
+                details: "leap seconds file has no data",
+            });
+        }
+
+        match LeapSecondsFormat::detect(contents) {
+            Some(format) => Self::from_str_with_format(contents, format),
+            None => Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details: "leap seconds file format could not be detected from its header or data",
+            }),
+        }
+    }
+
+    /// Builds a leap second provider from the contents of a leap seconds file in the provided format.
+    pub fn from_str_with_format(
+        contents: &str,
+        format: LeapSecondsFormat,
+    ) -> Result<Self, HifitimeError> {
+        match format {
+            LeapSecondsFormat::LeapSecondsList => Self::from_leap_seconds_list_str(contents),
+            LeapSecondsFormat::TaiUtcDat => Self::from_tai_utc_dat_str(contents),
+            LeapSecondsFormat::LeapSecondDat => Self::from_leap_second_dat_str(contents),
+        }
+    }
+
+    /// Builds a leap second provider from the contents of a leap seconds file in IERS format as found on <https://www.ietf.org/timezones/data/leap-seconds.list> .
+    ///
+    /// The last update time (`#$` line) and the expiration time (`#@` line) of the file are parsed, and if the file has a hash line (`#h`),
+    /// the SHA-1 hash of its data is verified: a mismatch returns a [ParsingError::HashMismatch] error.
+    ///
+    /// Errors on a given line are returned as [HifitimeError::ParseLine], and the leap seconds must be sorted by date.
+    pub fn from_leap_seconds_list_str(contents: &str) -> Result<Self, HifitimeError> {
         let mut me = Self::default();
+        // Data covered by the hash: the NTP timestamps of the special lines and the values of the data lines, without whitespace.
+        let mut hashed_data = String::new();
+        let mut expected_hash = None;
 
-        for line in contents.lines() {
-            if let Some(first_char) = line.chars().next() {
-                if first_char == '#' {
-                    continue;
-                } else {
-                    // We have data of interest!
-                    let data: Vec<&str> = line.split_whitespace().collect();
-                    if data.len() < 2 {
-                        return Err(HifitimeError::Parse {
-                            source: ParsingError::UnknownFormat,
-                            details: "leap seconds file should have two columns exactly",
-                        });
-                    }
+        for (idx, line) in contents.lines().enumerate() {
+            me.parse_leap_seconds_list_line(line, &mut hashed_data, &mut expected_hash)
+                .map_err(|err| Self::line_error(err, idx, line))?;
+        }
 
-                    let timestamp_tai_s: u64 = match lexical_core::parse(data[0].as_bytes()) {
-                        Ok(val) => val,
-                        Err(_) => {
-                            return Err(HifitimeError::Parse {
-                                source: ParsingError::ValueError,
-                                details: "first column value is not numeric",
-                            })
-                        }
-                    };
+        if let Some(expected_hash) = expected_hash {
+            if sha1(hashed_data.as_bytes()) != expected_hash {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::HashMismatch,
+                    details: "SHA-1 hash of the leap seconds file does not match its data",
+                });
+            }
+        }
 
-                    let delta_at: u8 = match lexical_core::parse(data[1].as_bytes()) {
-                        Ok(val) => val,
-                        Err(_) => {
+        Ok(me)
+    }
+
+    /// Parses the provided line of an IETF `leap-seconds.list`.
+    fn parse_leap_seconds_list_line(
+        &mut self,
+        line: &str,
+        hashed_data: &mut String,
+        expected_hash: &mut Option<[u32; 5]>,
+    ) -> Result<(), HifitimeError> {
+        if let Some(first_char) = line.chars().next() {
+            if first_char == '#' {
+                match line.get(1..2) {
+                    Some("$") | Some("@") => {
+                        let value = line[2..].trim();
+                        let timestamp_s: u64 = match lexical_core::parse(value.as_bytes()) {
+                            Ok(val) => val,
+                            Err(_) => {
+                                return Err(HifitimeError::Parse {
+                                    source: ParsingError::ValueError,
+                                    details:
+                                        "leap seconds file update or expiration time is not numeric",
+                                })
+                            }
+                        };
+                        hashed_data.push_str(value);
+                        let epoch = Epoch::from_utc_duration(Unit::Second * timestamp_s as f64);
+                        if &line[1..2] == "$" {
+                            self.last_update = Some(epoch);
+                        } else {
+                            self.expiration = Some(epoch);
+                        }
+                    }
+                    Some("h") => {
+                        let mut hash = [0_u32; 5];
+                        let words: Vec<&str> = line[2..].split_whitespace().collect();
+                        if words.len() != hash.len() {
                             return Err(HifitimeError::Parse {
-                                source: ParsingError::ValueError,
-                                details: "second column value is not numeric",
-                            })
+                                source: ParsingError::UnknownFormat,
+                                details: "leap seconds file hash should have five words",
+                            });
                         }
-                    };
-
-                    me.data.push(LeapSecond {
-                        timestamp_tai_s: (timestamp_tai_s as f64),
-                        delta_at: (delta_at as f64),
-                        announced_by_iers: true,
+                        for (word, hex) in hash.iter_mut().zip(words) {
+                            *word = match u32::from_str_radix(hex, 16) {
+                                Ok(val) => val,
+                                Err(_) => {
+                                    return Err(HifitimeError::Parse {
+                                        source: ParsingError::ValueError,
+                                        details: "leap seconds file hash is not hexadecimal",
+                                    })
+                                }
+                            };
+                        }
+                        *expected_hash = Some(hash);
+                    }
+                    _ => {}
+                }
+            } else {
+                // We have data of interest!
+                let data: Vec<&str> = line.split_whitespace().collect();
+                if data.len() < 2 {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "leap seconds file should have two columns exactly",
                     });
                 }
+
+                let timestamp_tai_s: u64 = match lexical_core::parse(data[0].as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "first column value is not numeric",
+                        })
+                    }
+                };
+
+                let delta_at: u8 = match lexical_core::parse(data[1].as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "second column value is not numeric",
+                        })
+                    }
+                };
+
+                hashed_data.push_str(data[0]);
+                hashed_data.push_str(data[1]);
+
+                self.push(LeapSecond::new(
+                    timestamp_tai_s as f64,
+                    delta_at as f64,
+                    true,
+                ))?;
             }
         }
+        Ok(())
+    }
+
+    /// Builds a leap second provider from the contents of a USNO `tai-utc.dat` file, as found on <https://maia.usno.navy.mil/ser7/tai-utc.dat> , e.g.
+    ///
+    /// ` 1961 JAN  1 =JD 2437300.5  TAI-UTC=   1.4228180 S + (MJD - 37300.) X 0.001296 S`
+    ///
+    /// The entries before 1972 keep the drift of UTC (cf. [LeapSecond::new_with_drift]), and are marked as not announced by the IERS.
+    /// Errors on a given line are returned as [HifitimeError::ParseLine], and the entries must be sorted by date.
+    pub fn from_tai_utc_dat_str(contents: &str) -> Result<Self, HifitimeError> {
+        let mut me = Self::default();
+
+        for (idx, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            Self::parse_tai_utc_dat_line(line)
+                .and_then(|leap_second| me.push(leap_second))
+                .map_err(|err| Self::line_error(err, idx, line))?;
+        }
 
         Ok(me)
     }
+
+    /// Parses the provided data line of a USNO `tai-utc.dat` file.
+    fn parse_tai_utc_dat_line(line: &str) -> Result<LeapSecond, HifitimeError> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        // Returns the number following the provided token.
+        let after = |marker: &str, details: &'static str| -> Result<f64, HifitimeError> {
+            let value = tokens
+                .iter()
+                .position(|token| *token == marker)
+                .and_then(|idx| tokens.get(idx + 1))
+                .ok_or(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details,
+                })?;
+            // The reference MJD is formatted as `37300.)`
+            let value = value.trim_end_matches(')').trim_end_matches('.');
+            lexical_core::parse(value.as_bytes()).map_err(|_| HifitimeError::Parse {
+                source: ParsingError::ValueError,
+                details,
+            })
+        };
+
+        let jd = after(
+            "=JD",
+            "tai-utc.dat line should have a numeric Julian date after `=JD`",
+        )?;
+        let delta_at = after(
+            "TAI-UTC=",
+            "tai-utc.dat line should have a numeric offset after `TAI-UTC=`",
+        )?;
+        let drift_ref_mjd = after(
+            "-",
+            "tai-utc.dat line should have a numeric reference MJD after `(MJD -`",
+        )?;
+        let drift_s_per_day = after(
+            "X",
+            "tai-utc.dat line should have a numeric drift rate after `X`",
+        )?;
+
+        let timestamp_tai_s = (jd - MJD_OFFSET - MJD_J1900) * SECONDS_PER_DAY;
+        Ok(if drift_s_per_day == 0.0 {
+            LeapSecond::new(timestamp_tai_s, delta_at, true)
+        } else {
+            LeapSecond::new_with_drift(timestamp_tai_s, delta_at, drift_ref_mjd, drift_s_per_day)
+        })
+    }
+
+    /// Builds a leap second provider from the contents of an IERS `Leap_Second.dat` file, as found on <https://hpiers.obspm.fr/iers/bul/bulc/Leap_Second.dat> ,
+    /// whose lines are the MJD, the day, the month, the year and TAI - UTC, e.g. `41317.0    1  1 1972       10`.
+    ///
+    /// Errors on a given line are returned as [HifitimeError::ParseLine], and the leap seconds must be sorted by date.
+    pub fn from_leap_second_dat_str(contents: &str) -> Result<Self, HifitimeError> {
+        let mut me = Self::default();
+
+        for (idx, line) in contents.lines().enumerate() {
+            if let Some(expiration) = line.split("File expires on").nth(1) {
+                // e.g. `#  File expires on 28 June 2025`
+                me.expiration = Some(
+                    Self::parse_expiration_date(expiration)
+                        .map_err(|err| Self::line_error(err, idx, line))?,
+                );
+                continue;
+            } else if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            Self::parse_leap_second_dat_line(line)
+                .and_then(|leap_second| me.push(leap_second))
+                .map_err(|err| Self::line_error(err, idx, line))?;
+        }
+
+        Ok(me)
+    }
+
+    /// Parses the provided data line of an IERS `Leap_Second.dat` file.
+    fn parse_leap_second_dat_line(line: &str) -> Result<LeapSecond, HifitimeError> {
+        let data: Vec<&str> = line.split_whitespace().collect();
+        if data.len() != 5 {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details:
+                    "Leap_Second.dat should have five columns: MJD, day, month, year and TAI-UTC",
+            });
+        }
+
+        let mjd: f64 = match lexical_core::parse(data[0].as_bytes()) {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details: "Leap_Second.dat MJD is not numeric",
+                })
+            }
+        };
+
+        let delta_at: u8 = match lexical_core::parse(data[4].as_bytes()) {
+            Ok(val) => val,
+            Err(_) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details: "Leap_Second.dat TAI-UTC is not an integer",
+                })
+            }
+        };
+
+        Ok(LeapSecond::new(
+            (mjd - MJD_J1900) * SECONDS_PER_DAY,
+            delta_at as f64,
+            true,
+        ))
+    }
+
+    /// Appends the provided leap second, which must be after the previous one.
+    fn push(&mut self, leap_second: LeapSecond) -> Result<(), HifitimeError> {
+        if self
+            .data
+            .last()
+            .is_some_and(|prev| prev.timestamp_tai_s >= leap_second.timestamp_tai_s)
+        {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::ValueError,
+                details: "leap seconds file is not sorted by date",
+            });
+        }
+        self.data.push(leap_second);
+        Ok(())
+    }
+
+    /// Adds the number (counted from one) and the content of the provided line to a parsing error.
+    fn line_error(err: HifitimeError, idx: usize, line: &str) -> HifitimeError {
+        match err {
+            HifitimeError::Parse { source, details } => HifitimeError::ParseLine {
+                source,
+                details,
+                line: idx + 1,
+                content: LineContent::new(line),
+            },
+            err => err,
+        }
+    }
+
+    /// Returns the last update of the leap second values of this file, if known (IETF `leap-seconds.list` only).
+    pub fn last_update(&self) -> Option<Epoch> {
+        self.last_update
+    }
+
+    /// Returns the expiration of this file, if known (IETF `leap-seconds.list` and IERS `Leap_Second.dat` only).
+    ///
+    /// A leap second may be introduced after this epoch, so UTC conversions past it may be off by a whole number of seconds.
+    pub fn expiration(&self) -> Option<Epoch> {
+        self.expiration
+    }
+
+    /// Returns whether the leap seconds of this file are known to be valid at the provided epoch, i.e. whether the epoch is before the expiration of this file.
+    ///
+    /// If the expiration of this file is unknown, e.g. for a USNO `tai-utc.dat` file, this always returns true.
+    pub fn is_valid_at(&self, epoch: Epoch) -> bool {
+        match self.expiration {
+            Some(expiration) => epoch < expiration,
+            None => true,
+        }
+    }
+
+    /// Parses the expiration date of an IERS `Leap_Second.dat` file, e.g. `28 June 2025`.
+    fn parse_expiration_date(date: &str) -> Result<Epoch, HifitimeError> {
+        let err = || HifitimeError::Parse {
+            source: ParsingError::ValueError,
+            details: "Leap_Second.dat expiration should be formatted as `DD Month YYYY`",
+        };
+        let parts: Vec<&str> = date.split_whitespace().collect();
+        if parts.len() != 3 {
+            return Err(err());
+        }
+        let day: u8 = lexical_core::parse(parts[0].as_bytes()).map_err(|_| err())?;
+        let month = MonthName::from_str(parts[1]).map_err(|_| err())?;
+        let year: i32 = lexical_core::parse(parts[2].as_bytes()).map_err(|_| err())?;
+        Epoch::maybe_from_gregorian(year, month as u8 + 1, day, 0, 0, 0, 0, TimeScale::UTC)
+            .map_err(|_| err())
+    }
 }
 
 #[cfg(feature = "python")]
@@ -140,7 +529,18 @@ impl Index<usize> for LeapSecondsFile {
     }
 }
 
-impl LeapSecondProvider for LeapSecondsFile {}
+impl LeapSecondTable for LeapSecondsFile {
+    fn as_slice(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        Some(&self.cache)
+    }
+}
+
+#[allow(deprecated)]
+impl crate::leap_seconds::LeapSecondProvider for LeapSecondsFile {}
 
 #[test]
 fn leap_second_fetch() {
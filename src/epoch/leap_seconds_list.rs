@@ -0,0 +1,385 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::ops::Index;
+
+use super::sha1::Sha1;
+use crate::{
+    leap_seconds::{LeapSecond, LeapSecondTable, LookupCache},
+    Epoch, HifitimeError, ParsingError, Unit,
+};
+
+/// Declares a static [LeapSecondsList](crate::leap_seconds::LeapSecondsList) parsed at compile time from the contents of an IETF `leap-seconds.list`,
+/// typically embedded with `include_str!`. This works without the standard library and without allocation.
+///
+/// The number of leap seconds of the list is that of the data lines of the file, cf. [leap_seconds_list_len](crate::leap_seconds::leap_seconds_list_len),
+/// and a malformed or unsorted file, or one whose hash does not match its data, fails to build with the reason of the failure.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::LeapSecondTable;
+/// use hifitime::{leap_seconds_list, Epoch, TimeScale};
+///
+/// leap_seconds_list!(
+///     static LEAP_SECONDS = "#@\t3960057600
+/// 2272060800\t10\t# 1 Jan 1972
+/// 3692217600\t37\t# 1 Jan 2017
+/// "
+/// );
+///
+/// assert_eq!(LEAP_SECONDS.as_slice().len(), 2);
+/// assert_eq!(LEAP_SECONDS.expiration(), Some(Epoch::from_gregorian_utc_at_midnight(2025, 6, 28)));
+///
+/// let epoch = Epoch::from_gregorian_tai_at_midnight(2020, 1, 1);
+/// let utc = epoch.to_time_scale_with_leap_seconds(TimeScale::UTC, &LEAP_SECONDS);
+/// assert_eq!(format!("{utc}"), "2019-12-31T23:59:23 UTC");
+/// ```
+///
+/// Leap seconds which are not sorted by date do not build:
+/// ```compile_fail
+/// hifitime::leap_seconds_list!(
+///     static LEAP_SECONDS = "3692217600\t37\n2272060800\t10\n"
+/// );
+/// ```
+#[macro_export]
+macro_rules! leap_seconds_list {
+    ($(#[$attr:meta])* $vis:vis static $name:ident = $contents:expr $(;)?) => {
+        $(#[$attr])*
+        $vis static $name: $crate::leap_seconds::LeapSecondsList<
+            { $crate::leap_seconds::leap_seconds_list_len($contents) },
+        > = match $crate::leap_seconds::LeapSecondsList::from_leap_seconds_list_str($contents) {
+            ::core::result::Result::Ok(list) => list,
+            ::core::result::Result::Err($crate::HifitimeError::Parse { details, .. }) => {
+                ::core::panic!("{}", details)
+            }
+            ::core::result::Result::Err(_) => ::core::panic!("invalid leap seconds list"),
+        };
+    };
+}
+
+/// Returns the number of leap seconds of the provided IETF `leap-seconds.list`, i.e. the number of its lines which are neither blank nor comments.
+pub const fn leap_seconds_list_len(contents: &str) -> usize {
+    let bytes = contents.as_bytes();
+    let mut count = 0;
+    let mut start = 0;
+    while start < bytes.len() {
+        let end = line_end(bytes, start);
+        if skip_spaces(bytes, start, end) < end && bytes[start] != b'#' {
+            count += 1;
+        }
+        start = end + 1;
+    }
+    count
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// A leap second provider of the `N` leap seconds of an IETF `leap-seconds.list` parsed in a const context, such that they can be embedded at compile time,
+/// e.g. with the [leap_seconds_list!](crate::leap_seconds_list) macro, without the standard library and without allocation.
+///
+/// # Example
+/// ```
+/// use hifitime::leap_seconds::LeapSecondsList;
+/// use hifitime::Epoch;
+///
+/// const LEAP_SECONDS: LeapSecondsList<2> = match LeapSecondsList::from_leap_seconds_list_str(
+///     "2272060800\t10\t# 1 Jan 1972\n3692217600\t37\t# 1 Jan 2017\n",
+/// ) {
+///     Ok(list) => list,
+///     Err(_) => panic!("invalid leap seconds list"),
+/// };
+///
+/// let epoch = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+/// assert_eq!(epoch.leap_seconds_with(true, &LEAP_SECONDS), Some(37.0));
+/// ```
+pub struct LeapSecondsList<const N: usize> {
+    data: [LeapSecond; N],
+    last_update_ntp: Option<u64>,
+    expiration_ntp: Option<u64>,
+    cache: LookupCache,
+}
+
+impl<const N: usize> LeapSecondsList<N> {
+    /// Parses the contents of an IETF `leap-seconds.list`, in the same way as [LeapSecondsFile::from_leap_seconds_list_str](crate::leap_seconds::LeapSecondsFile::from_leap_seconds_list_str)
+    /// but in a const context, which must have exactly `N` leap seconds sorted by date.
+    ///
+    /// Each data line must only have the NTP timestamp and ΔAT of a leap second, optionally followed by a comment, and if the file has a hash line (`#h`),
+    /// the SHA-1 hash of its data is verified: a mismatch returns a [ParsingError::HashMismatch] error.
+    pub const fn from_leap_seconds_list_str(contents: &str) -> Result<Self, HifitimeError> {
+        let bytes = contents.as_bytes();
+        let mut data = [LeapSecond::new(0.0, 0.0, true); N];
+        let mut len = 0;
+        let mut last_timestamp = 0;
+        let mut last_update_ntp = None;
+        let mut expiration_ntp = None;
+        let mut expected_hash = None;
+        // Data covered by the hash: the NTP timestamps of the special lines and the values of the data lines, without whitespace.
+        let mut hasher = Sha1::new();
+
+        let mut start = 0;
+        while start < bytes.len() {
+            let end = line_end(bytes, start);
+            let first = skip_spaces(bytes, start, end);
+
+            if first == end {
+                // Blank line
+            } else if bytes[start] == b'#' {
+                let kind = if start + 1 < end {
+                    bytes[start + 1]
+                } else {
+                    b'#'
+                };
+                if kind == b'$' || kind == b'@' {
+                    let value_start = skip_spaces(bytes, start + 2, end);
+                    let (value, value_end) = match parse_decimal(bytes, value_start, end) {
+                        Some(parsed) if skip_spaces(bytes, parsed.1, end) == end => parsed,
+                        _ => {
+                            return Err(HifitimeError::Parse {
+                                source: ParsingError::ValueError,
+                                details:
+                                    "leap seconds file update or expiration time is not numeric",
+                            })
+                        }
+                    };
+                    hasher.update(subslice(bytes, value_start, value_end));
+                    if kind == b'$' {
+                        last_update_ntp = Some(value);
+                    } else {
+                        expiration_ntp = Some(value);
+                    }
+                } else if kind == b'h' {
+                    let mut hash = [0_u32; 5];
+                    let mut pos = start + 2;
+                    let mut word = 0;
+                    while word < hash.len() {
+                        match parse_hex(bytes, skip_spaces(bytes, pos, end), end) {
+                            Some((value, word_end)) => {
+                                hash[word] = value;
+                                pos = word_end;
+                            }
+                            None => {
+                                return Err(HifitimeError::Parse {
+                                    source: ParsingError::ValueError,
+                                    details:
+                                        "leap seconds file hash should have five hexadecimal words",
+                                })
+                            }
+                        }
+                        word += 1;
+                    }
+                    if skip_spaces(bytes, pos, end) != end {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::UnknownFormat,
+                            details: "leap seconds file hash should have five words",
+                        });
+                    }
+                    expected_hash = Some(hash);
+                }
+            } else {
+                let (timestamp, timestamp_end) = match parse_decimal(bytes, first, end) {
+                    Some(parsed) => parsed,
+                    None => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "first column value is not numeric",
+                        })
+                    }
+                };
+                let delta_at_start = skip_spaces(bytes, timestamp_end, end);
+                if delta_at_start == timestamp_end || delta_at_start == end {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "leap seconds file should have two columns exactly",
+                    });
+                }
+                let (delta_at, delta_at_end) = match parse_decimal(bytes, delta_at_start, end) {
+                    Some(parsed) => parsed,
+                    None => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "second column value is not numeric",
+                        })
+                    }
+                };
+                let comment = skip_spaces(bytes, delta_at_end, end);
+                if comment < end && (comment == delta_at_end || bytes[comment] != b'#') {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "leap seconds file should have two columns exactly",
+                    });
+                }
+
+                if len > 0 && timestamp <= last_timestamp {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::ValueError,
+                        details: "leap seconds file is not sorted by date",
+                    });
+                }
+                if len == N {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "leap seconds file has more leap seconds than the list",
+                    });
+                }
+
+                hasher.update(subslice(bytes, first, timestamp_end));
+                hasher.update(subslice(bytes, delta_at_start, delta_at_end));
+                data[len] = LeapSecond::new(timestamp as f64, delta_at as f64, true);
+                last_timestamp = timestamp;
+                len += 1;
+            }
+
+            start = end + 1;
+        }
+
+        if len == 0 {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::NothingToParse,
+                details: "leap seconds file has no leap seconds",
+            });
+        }
+        if len != N {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::UnknownFormat,
+                details: "leap seconds file has fewer leap seconds than the list",
+            });
+        }
+
+        if let Some(expected_hash) = expected_hash {
+            let hash = hasher.finish();
+            let mut word = 0;
+            while word < hash.len() {
+                if hash[word] != expected_hash[word] {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::HashMismatch,
+                        details: "SHA-1 hash of the leap seconds file does not match its data",
+                    });
+                }
+                word += 1;
+            }
+        }
+
+        Ok(Self {
+            data,
+            last_update_ntp,
+            expiration_ntp,
+            cache: LookupCache::new(),
+        })
+    }
+
+    /// Returns the last update of the leap second values of this list (`#$` line), if any.
+    pub fn last_update(&self) -> Option<Epoch> {
+        self.last_update_ntp
+            .map(|ntp| Epoch::from_utc_duration(Unit::Second * ntp as f64))
+    }
+
+    /// Returns the expiration of this list (`#@` line), if any.
+    ///
+    /// A leap second may be introduced after this epoch, so UTC conversions past it may be off by a whole number of seconds.
+    pub fn expiration(&self) -> Option<Epoch> {
+        self.expiration_ntp
+            .map(|ntp| Epoch::from_utc_duration(Unit::Second * ntp as f64))
+    }
+
+    /// Returns whether the leap seconds of this list are known to be valid at the provided epoch, i.e. whether the epoch is before the expiration of this list.
+    ///
+    /// If the expiration of this list is unknown, this always returns true.
+    pub fn is_valid_at(&self, epoch: Epoch) -> bool {
+        match self.expiration() {
+            Some(expiration) => epoch < expiration,
+            None => true,
+        }
+    }
+}
+
+impl<const N: usize> Index<usize> for LeapSecondsList<N> {
+    type Output = LeapSecond;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl<const N: usize> LeapSecondTable for LeapSecondsList<N> {
+    fn as_slice(&self) -> &[LeapSecond] {
+        &self.data
+    }
+
+    fn lookup_cache(&self) -> Option<&LookupCache> {
+        Some(&self.cache)
+    }
+}
+
+/// Returns the index of the line feed ending the line starting at the provided index, or the length of the contents for the last line.
+const fn line_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && bytes[i] != b'\n' {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the index of the first byte from the provided index which is not whitespace, or the end of the line.
+const fn skip_spaces(bytes: &[u8], start: usize, end: usize) -> usize {
+    let mut i = start;
+    while i < end && matches!(bytes[i], b' ' | b'\t' | b'\r') {
+        i += 1;
+    }
+    i
+}
+
+/// Parses the decimal number starting at the provided index, returning it with the index following its last digit.
+const fn parse_decimal(bytes: &[u8], start: usize, end: usize) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    let mut i = start;
+    while i < end && bytes[i].is_ascii_digit() {
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[i] - b'0') as u64) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    if i == start {
+        None
+    } else {
+        Some((value, i))
+    }
+}
+
+/// Parses the hexadecimal 32-bit word starting at the provided index, returning it with the index following its last digit.
+const fn parse_hex(bytes: &[u8], start: usize, end: usize) -> Option<(u32, usize)> {
+    let mut value: u32 = 0;
+    let mut i = start;
+    while i < end {
+        let digit = match bytes[i] {
+            b'0'..=b'9' => bytes[i] - b'0',
+            b'a'..=b'f' => bytes[i] - b'a' + 10,
+            b'A'..=b'F' => bytes[i] - b'A' + 10,
+            _ => break,
+        };
+        if i - start == 8 {
+            return None;
+        }
+        value = (value << 4) | digit as u32;
+        i += 1;
+    }
+    if i == start {
+        None
+    } else {
+        Some((value, i))
+    }
+}
+
+/// Returns the bytes between the provided indices.
+const fn subslice(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    bytes.split_at(end).0.split_at(start).1
+}
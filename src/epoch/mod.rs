@@ -8,33 +8,59 @@
  * Documentation: https://nyxspace.com/
  */
 
+mod delta_t;
+mod et;
 mod formatting;
 mod gregorian;
+mod leap_seconds_list;
 mod ops;
+mod sha1;
+mod sidereal;
+mod smear;
+mod solar_time;
+mod tdb;
 mod with_funcs;
 
+pub use et::EtModel;
+pub use tdb::{TdbModel, TdbObserver};
+
+#[cfg(feature = "std")]
+mod circular_t;
+#[cfg(feature = "std")]
+mod leap_second_kernel;
+#[cfg(feature = "std")]
+mod leap_seconds_export;
 #[cfg(feature = "std")]
 mod leap_seconds_file;
 #[cfg(feature = "std")]
 mod system_time;
+#[cfg(feature = "std")]
+mod tt_bipm;
+
+#[cfg(feature = "std")]
+pub use circular_t::{CircularT, UtcRealization};
+#[cfg(feature = "std")]
+pub use tt_bipm::{TtBipmCorrection, TtBipmFile};
 
 #[cfg(kani)]
 mod kani_verif;
 
-#[cfg(feature = "ut1")]
-#[cfg_attr(docrs, doc(cfg(feature = "ut1")))]
+#[cfg(feature = "std")]
+#[cfg_attr(docrs, doc(cfg(feature = "std")))]
 pub mod ut1;
 
 pub mod leap_seconds;
 
-use crate::duration::{Duration, Unit};
+use crate::duration::{Duration, Unit, NANOSECONDS_PER_MILLISECOND};
 use crate::efmt::format::Format;
 use crate::errors::{DurationError, ParseSnafu};
-use crate::leap_seconds::{LatestLeapSeconds, LeapSecondProvider};
+use crate::leap_seconds::{LeapSecondSlice, LeapSecondTable};
 use crate::Weekday;
 use crate::{
-    HifitimeError, MonthName, TimeScale, TimeUnits, BDT_REF_EPOCH, ET_EPOCH_S, GPST_REF_EPOCH,
-    GST_REF_EPOCH, MJD_J1900, MJD_OFFSET, NANOSECONDS_PER_DAY, QZSST_REF_EPOCH, UNIX_REF_EPOCH,
+    HifitimeError, MonthName, TimeScale, TimeUnits, BDT_REF_EPOCH, ET_EPOCH_S, GLONASST_UTC_OFFSET,
+    GPST_REF_EPOCH, GST_REF_EPOCH, IAU_1977_REF_EPOCH, IAU_L_B, IAU_L_G, IAU_TDB0_S,
+    IRNWT_REF_EPOCH, JD_J2000, LTC_PERIODIC_AMPLITUDE_S, LTC_TT_RATE, MJD_J1900, MJD_J2000,
+    MJD_OFFSET, NANOSECONDS_PER_DAY, QZSST_REF_EPOCH, UNIX_REF_EPOCH,
 };
 use core::cmp::Eq;
 use core::str::FromStr;
@@ -63,6 +89,26 @@ use num_traits::Float;
 
 pub(crate) const TT_OFFSET_MS: i64 = 32_184;
 pub(crate) const ET_OFFSET_US: i64 = 32_184_935;
+/// Mean eccentricity of the lunar orbit, used to compute the periodic term of LTC.
+const LUNAR_ECCENTRICITY: f64 = 0.0549;
+/// Number of days in a GLONASS four-year interval, which starts with a leap year.
+const GLONASST_DAYS_PER_FOUR_YEARS: i128 = 1461;
+/// Ratio of a Martian mean solar day (sol) to an Earth day.
+const MARS_DAYS_PER_SOL: f64 = 1.027_491_251_7;
+/// Mars Sol Date on 2000 January 6 at midnight TT, including the Mars24 adjustment that places the Airy-0 crater on the prime meridian.
+const MARS_SOL_DATE_REF: f64 = 44_796.0 - 0.000_962_6;
+/// Number of days between J2000 TT and the reference epoch of the Mars Sol Date.
+const MARS_SOL_DATE_REF_DAYS_J2000: f64 = 4.5;
+/// Amplitude (deg), period (Julian years) and phase (deg) of the planetary perturbations of the Martian orbit, cf. Allison & McEwen (2000) table 5.
+const MARS_PERTURBATIONS: [(f64, f64, f64); 7] = [
+    (0.0071, 2.2353, 49.409),
+    (0.0057, 2.7543, 168.173),
+    (0.0039, 1.1177, 191.837),
+    (0.0037, 15.7866, 21.736),
+    (0.0021, 2.1354, 15.704),
+    (0.0020, 2.4694, 95.528),
+    (0.0018, 32.8493, 49.095),
+];
 
 /// NAIF leap second kernel data for M_0 used to calculate the mean anomaly of the heliocentric orbit of the Earth-Moon barycenter.
 pub const NAIF_M0: f64 = 6.239996;
@@ -120,6 +166,66 @@ impl Epoch {
     /// As per the [Rust naming convention](https://rust-lang.github.io/api-guidelines/naming.html#ad-hoc-conversions-follow-as_-to_-into_-conventions-c-conv),
     /// this borrows an Epoch and returns an owned Epoch.
     pub fn to_time_scale(&self, ts: TimeScale) -> Self {
+        #[cfg(feature = "std")]
+        {
+            if ts != self.time_scale
+                && (Self::depends_on_leap_seconds(ts)
+                    || Self::depends_on_leap_seconds(self.time_scale))
+            {
+                if let Some(installed) = leap_seconds::installed_leap_seconds() {
+                    return self.to_time_scale_with_leap_seconds(
+                        ts,
+                        &LeapSecondSlice::installed(&installed),
+                    );
+                }
+            }
+        }
+        self.to_time_scale_with_leap_seconds(ts, &LeapSecondSlice::latest())
+    }
+
+    /// Converts self to another time scale like [Epoch::to_time_scale], but returns an error when converting from or into [TimeScale::UT1]
+    /// without any installed UT1 data at this epoch, instead of assuming that UT1 matches UTC.
+    ///
+    /// Converting from or into UT1 requires the `std` feature and an installed provider, cf. `Ut1Provider::install`.
+    pub fn try_to_time_scale(&self, ts: TimeScale) -> Result<Self, HifitimeError> {
+        if ts != self.time_scale && (ts == TimeScale::UT1 || self.time_scale == TimeScale::UT1) {
+            let tai_duration = self.to_tai_duration();
+            #[cfg(feature = "std")]
+            let has_ut1_data = ut1::installed_delta_tai_minus_ut1(tai_duration).is_some();
+            #[cfg(not(feature = "std"))]
+            let has_ut1_data = false;
+            if !has_ut1_data {
+                return Err(HifitimeError::Ut1Unavailable {
+                    epoch: Self::from_tai_duration(tai_duration),
+                });
+            }
+        }
+        Ok(self.to_time_scale(ts))
+    }
+
+    #[must_use]
+    /// Converts self to another time scale using the leap seconds of the provided provider, e.g. a [LeapSecondsFile](crate::leap_seconds::LeapSecondsFile),
+    /// instead of the installed or built-in leap seconds, for the conversions from or into UTC and the time scales derived from it.
+    ///
+    /// This also works without the standard library. The returned Epoch does not keep the provider: an Epoch converted into [TimeScale::UTC] with this function
+    /// stores the reading of UTC computed with the provided leap seconds, and is displayed as such, but any further conversion (e.g. [Epoch::to_time_scale],
+    /// [Epoch::to_gregorian_utc] or formatting it in another time scale), as well as finding whether it is within a leap second (`23:59:60`),
+    /// uses the installed or built-in leap seconds. Convert it again with this function to keep using the provided leap seconds.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::leap_seconds::LatestLeapSeconds;
+    /// use hifitime::{Epoch, TimeScale};
+    ///
+    /// let epoch = Epoch::from_gregorian_tai_at_midnight(2017, 1, 1);
+    /// let utc = epoch.to_time_scale_with_leap_seconds(TimeScale::UTC, &LatestLeapSeconds::default());
+    /// assert_eq!(format!("{utc}"), "2016-12-31T23:59:24 UTC");
+    /// ```
+    pub fn to_time_scale_with_leap_seconds<L: LeapSecondTable + ?Sized>(
+        &self,
+        ts: TimeScale,
+        provider: &L,
+    ) -> Self {
         if ts == self.time_scale {
             // Do nothing, just return a copy
             *self
@@ -129,26 +235,7 @@ impl Epoch {
             let prime_epoch_offset = match self.time_scale {
                 TimeScale::TAI => self.duration,
                 TimeScale::TT => self.duration - TT_OFFSET_MS.milliseconds(),
-                TimeScale::ET => {
-                    // Run a Newton Raphston to convert find the correct value of the
-                    let mut seconds_j2000 = self.duration.to_seconds();
-                    for _ in 0..5 {
-                        seconds_j2000 += -NAIF_K
-                            * (NAIF_M0
-                                + NAIF_M1 * seconds_j2000
-                                + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds_j2000).sin())
-                            .sin();
-                    }
-
-                    // At this point, we have a good estimate of the number of seconds of this epoch.
-                    // Reverse the algorithm:
-                    let delta_et_tai = Self::delta_et_tai(
-                        seconds_j2000 - (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
-                    );
-
-                    // Match SPICE by changing the UTC definition.
-                    self.duration - delta_et_tai.seconds() + self.time_scale.prime_epoch_offset()
-                }
+                TimeScale::ET => EtModel::default().et_to_tai(self.duration),
                 TimeScale::TDB => {
                     let gamma = Self::inner_g(self.duration.to_seconds());
 
@@ -158,42 +245,64 @@ impl Epoch {
                     self.duration - delta_tdb_tai + self.time_scale.prime_epoch_offset()
                 }
                 TimeScale::UTC => {
-                    // Assume this is TAI
-                    let mut tai_assumption = *self;
-                    tai_assumption.time_scale = TimeScale::TAI;
-                    self.duration + tai_assumption.leap_seconds(true).unwrap_or(0.0).seconds()
+                    // Before 1972, TAI - UTC drifted and changed by fractions of a second, as per SOFA `iauDat`.
+                    self.duration
+                        + Self::delta_at_duration(
+                            self.leap_seconds_with(false, provider).unwrap_or(0.0),
+                        )
                 }
                 TimeScale::GPST => self.duration + GPST_REF_EPOCH.to_tai_duration(),
                 TimeScale::GST => self.duration + GST_REF_EPOCH.to_tai_duration(),
                 TimeScale::BDT => self.duration + BDT_REF_EPOCH.to_tai_duration(),
                 TimeScale::QZSST => self.duration + QZSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::IRNWT => self.duration + IRNWT_REF_EPOCH.to_tai_duration(),
+                TimeScale::UTCSLS | TimeScale::UTCSMEAR => {
+                    Self::smeared_utc_to_tai(self.duration, self.time_scale, provider)
+                }
+                TimeScale::TCG => {
+                    // TT = TCG - L_G × (TCG - T0), where T0 has the same reading in TT and TCG.
+                    let t0 = Self::tcg_tcb_t0_tt();
+                    let tt = self.duration
+                        - ((self.duration - t0).to_seconds() * IAU_L_G) * Unit::Second;
+                    tt - TT_OFFSET_MS.milliseconds()
+                }
+                TimeScale::TCB => {
+                    // TDB = TCB - L_B × (TCB - T0) + TDB0, where T0 is expressed since J2000 like TCB.
+                    let t0 = Self::tcg_tcb_t0_tt() - self.time_scale.prime_epoch_offset();
+                    let tdb = self.duration
+                        - ((self.duration - t0).to_seconds() * IAU_L_B) * Unit::Second
+                        + IAU_TDB0_S * Unit::Second;
+                    Self::from_tdb_duration(tdb).to_tai_duration()
+                }
+                TimeScale::UT1 => {
+                    // TAI = UT1 + (TAI - UT1), where the offset is first evaluated at the UT1 duration to approximate the TAI duration.
+                    let tai_approx =
+                        self.duration + Self::delta_tai_minus_ut1(self.duration, provider);
+                    self.duration + Self::delta_tai_minus_ut1(tai_approx, provider)
+                }
+                TimeScale::LTC => {
+                    // LTC = TT + Δ(TT): the correction varies so slowly that a fixed point iteration converges immediately.
+                    let mut tt = self.duration;
+                    for _ in 0..3 {
+                        tt = self.duration - Self::delta_ltc_tt(tt);
+                    }
+                    tt - TT_OFFSET_MS.milliseconds()
+                }
+                TimeScale::GLONASST => {
+                    // GLONASST = UTC + 3h
+                    Self::from_utc_duration(
+                        self.duration + self.time_scale.prime_epoch_offset() - GLONASST_UTC_OFFSET,
+                    )
+                    .to_time_scale_with_leap_seconds(TimeScale::TAI, provider)
+                    .duration
+                }
             };
 
             // Convert to the desired time scale from the TAI duration
             let ts_ref_offset = match ts {
                 TimeScale::TAI => prime_epoch_offset,
                 TimeScale::TT => prime_epoch_offset + TT_OFFSET_MS.milliseconds(),
-                TimeScale::ET => {
-                    // Run a Newton Raphston to convert find the correct value of the ... ?!
-
-                    let mut seconds = (prime_epoch_offset - ts.prime_epoch_offset()).to_seconds();
-                    for _ in 0..5 {
-                        seconds -= -NAIF_K
-                            * (NAIF_M0
-                                + NAIF_M1 * seconds
-                                + NAIF_EB * (NAIF_M0 + NAIF_M1 * seconds).sin())
-                            .sin();
-                    }
-
-                    // At this point, we have a good estimate of the number of seconds of this epoch.
-                    // Reverse the algorithm:
-                    let delta_et_tai = Self::delta_et_tai(
-                        seconds + (TT_OFFSET_MS * Unit::Millisecond).to_seconds(),
-                    );
-
-                    // Match SPICE by changing the UTC definition.
-                    prime_epoch_offset + delta_et_tai.seconds() - ts.prime_epoch_offset()
-                }
+                TimeScale::ET => EtModel::default().tai_to_et(prime_epoch_offset),
                 TimeScale::TDB => {
                     // Iterate to convert find the correct value of the
                     let mut seconds = (prime_epoch_offset - ts.prime_epoch_offset()).to_seconds();
@@ -223,12 +332,46 @@ impl Epoch {
                         time_scale: TimeScale::TAI,
                     };
                     // TAI = UTC + leap_seconds <=> UTC = TAI - leap_seconds
-                    prime_epoch_offset - epoch.leap_seconds(true).unwrap_or(0.0).seconds()
+                    prime_epoch_offset
+                        - Self::delta_at_duration(
+                            epoch.leap_seconds_with(false, provider).unwrap_or(0.0),
+                        )
                 }
                 TimeScale::GPST => prime_epoch_offset - GPST_REF_EPOCH.to_tai_duration(),
                 TimeScale::GST => prime_epoch_offset - GST_REF_EPOCH.to_tai_duration(),
                 TimeScale::BDT => prime_epoch_offset - BDT_REF_EPOCH.to_tai_duration(),
                 TimeScale::QZSST => prime_epoch_offset - QZSST_REF_EPOCH.to_tai_duration(),
+                TimeScale::IRNWT => prime_epoch_offset - IRNWT_REF_EPOCH.to_tai_duration(),
+                TimeScale::UTCSLS | TimeScale::UTCSMEAR => {
+                    Self::tai_to_smeared_utc(prime_epoch_offset, ts, provider)
+                }
+                TimeScale::TCG => {
+                    // TCG = TT + L_G / (1 - L_G) × (TT - T0)
+                    let t0 = Self::tcg_tcb_t0_tt();
+                    let tt = prime_epoch_offset + TT_OFFSET_MS.milliseconds();
+                    tt + ((tt - t0).to_seconds() * IAU_L_G / (1.0 - IAU_L_G)) * Unit::Second
+                }
+                TimeScale::TCB => {
+                    // TCB = TDB - TDB0 + L_B / (1 - L_B) × (TDB - TDB0 - T0)
+                    let t0 = Self::tcg_tcb_t0_tt() - ts.prime_epoch_offset();
+                    let tdb = Self::from_tai_duration(prime_epoch_offset).to_tdb_duration()
+                        - IAU_TDB0_S * Unit::Second;
+                    tdb + ((tdb - t0).to_seconds() * IAU_L_B / (1.0 - IAU_L_B)) * Unit::Second
+                }
+                TimeScale::UT1 => {
+                    prime_epoch_offset - Self::delta_tai_minus_ut1(prime_epoch_offset, provider)
+                }
+                TimeScale::LTC => {
+                    let tt = prime_epoch_offset + TT_OFFSET_MS.milliseconds();
+                    tt + Self::delta_ltc_tt(tt)
+                }
+                TimeScale::GLONASST => {
+                    Self::from_tai_duration(prime_epoch_offset)
+                        .to_time_scale_with_leap_seconds(TimeScale::UTC, provider)
+                        .duration
+                        + GLONASST_UTC_OFFSET
+                        - ts.prime_epoch_offset()
+                }
             };
 
             Self {
@@ -247,24 +390,27 @@ impl Epoch {
         }
     }
 
-    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
+    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondTable.
     /// Returns None if the epoch is before 1960, year at which UTC was defined.
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
-    pub fn leap_seconds_with<L: LeapSecondProvider>(
+    pub fn leap_seconds_with<L: LeapSecondTable>(
         &self,
         iers_only: bool,
         provider: L,
     ) -> Option<f64> {
-        for leap_second in provider.rev() {
-            if self.to_tai_duration().to_seconds() >= leap_second.timestamp_tai_s
-                && (!iers_only || leap_second.announced_by_iers)
-            {
-                return Some(leap_second.delta_at);
-            }
+        // UTC epochs are compared with their readings, and between 1961 and 1972, ΔAT drifted with the date in UTC.
+        if self.time_scale == TimeScale::UTC {
+            leap_seconds::find_leap_second(&provider, self.duration, true, iers_only).map(
+                |leap_second| {
+                    leap_second.delta_at_on_mjd_utc(self.duration.to_unit(Unit::Day) + MJD_J1900)
+                },
+            )
+        } else {
+            leap_seconds::find_leap_second(&provider, self.to_tai_duration(), false, iers_only)
+                .map(|leap_second| leap_second.delta_at_on_mjd_tai(self.to_mjd_tai_days()))
         }
-        None
     }
 
     /// Creates an epoch from given duration expressed in given timescale, i.e. since the given time scale's reference epoch.
@@ -508,6 +654,106 @@ impl Epoch {
         Self::from_jde_tai(days) - Unit::Microsecond * ET_OFFSET_US
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided Mars Sol Date (MSD), i.e. the number of sols since 1873 December 29 at noon, as defined by Allison & McEwen (2000).
+    pub fn from_mars_sol_date(msd: f64) -> Self {
+        assert!(
+            msd.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        let days_j2000 =
+            (msd - MARS_SOL_DATE_REF) * MARS_DAYS_PER_SOL + MARS_SOL_DATE_REF_DAYS_J2000;
+        Self::from_tt_duration(Unit::Second * ET_EPOCH_S + Unit::Day * days_j2000)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) duration since 1900 January 01 at midnight
+    pub fn from_tcg_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::TCG)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) seconds since 1900 January 01 at midnight
+    pub fn from_tcg_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) in JD days
+    pub fn from_jde_tcg(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration((days - MJD_J1900 - MJD_OFFSET) * Unit::Day)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) in MJD days
+    pub fn from_mjd_tcg(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcg_duration((days - MJD_J1900) * Unit::Day)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Coordinated Lunar Time (LTC) duration since 1900 January 01 at midnight
+    pub fn from_ltc_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::LTC)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Coordinated Lunar Time (LTC) seconds since 1900 January 01 at midnight
+    pub fn from_ltc_seconds(seconds: f64) -> Self {
+        assert!(
+            seconds.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_ltc_duration(seconds * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) duration past 2000 JAN 01 noon (J2000 reference)
+    pub fn from_tcb_duration(duration_since_j2000: Duration) -> Self {
+        Self::from_duration(duration_since_j2000, TimeScale::TCB)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) seconds past 2000 JAN 01 noon (J2000 reference)
+    pub fn from_tcb_seconds(seconds_j2000: f64) -> Self {
+        assert!(
+            seconds_j2000.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration(seconds_j2000 * Unit::Second)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) in JD days
+    pub fn from_jde_tcb(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration((days - JD_J2000) * Unit::Day)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) in MJD days
+    pub fn from_mjd_tcb(days: f64) -> Self {
+        assert!(
+            days.is_finite(),
+            "Attempted to initialize Epoch with non finite number"
+        );
+        Self::from_tcb_duration((days - MJD_J2000) * Unit::Day)
+    }
+
     #[must_use]
     /// Initialize an Epoch from the number of seconds since the GPS Time Epoch,
     /// defined as UTC midnight of January 5th to 6th 1980 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GPS_Time_.28GPST.29>).
@@ -598,6 +844,78 @@ impl Epoch {
         Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    pub fn from_irnwt_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::IRNWT)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    pub fn from_irnwt_seconds(seconds: f64) -> Self {
+        Self::from_duration(seconds * Unit::Second, TimeScale::IRNWT)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    pub fn from_irnwt_days(days: f64) -> Self {
+        Self::from_duration(days * Unit::Day, TimeScale::IRNWT)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    /// This may be useful for time keeping devices that use NavIC as a time source.
+    pub fn from_irnwt_nanoseconds(nanoseconds: u64) -> Self {
+        Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::IRNWT)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since the GLONASST Time Epoch, 1996 January 01 at midnight (GLONASST)
+    pub fn from_glonasst_duration(duration: Duration) -> Self {
+        Self::from_duration(duration, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of seconds since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    pub fn from_glonasst_seconds(seconds: f64) -> Self {
+        Self::from_duration(seconds * Unit::Second, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of days since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    pub fn from_glonasst_days(days: f64) -> Self {
+        Self::from_duration(days * Unit::Day, TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the number of nanoseconds since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    /// This may be useful for time keeping devices that use GLONASS as a time source.
+    pub fn from_glonasst_nanoseconds(nanoseconds: u64) -> Self {
+        Self::from_duration(Duration::from_parts(0, nanoseconds), TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Builds a GLONASST Epoch from its GLONASS representation, as broadcast in the navigation message:
+    /// + `n4`: the four-year interval number, starting at 1 for 1996 to 1999;
+    /// + `nt`: the calendar day number within that four-year interval, from 1 (January 1st of the leap year) to 1461;
+    /// + `time_of_day`: the time elapsed since midnight GLONASST (i.e. Moscow time) on that day.
+    ///
+    /// # Limitations
+    /// The four-year intervals are assumed to all include a leap year, which is true from 1901 until 2099.
+    pub fn from_glonasst_n4_nt(n4: i32, nt: u16, time_of_day: Duration) -> Self {
+        let days = i128::from(n4 - 1) * GLONASST_DAYS_PER_FOUR_YEARS + i128::from(nt) - 1;
+        Self::from_glonasst_duration(
+            Duration::from_total_nanoseconds(days * i128::from(NANOSECONDS_PER_DAY)) + time_of_day,
+        )
+    }
+
     #[must_use]
     /// Initialize an Epoch from the provided duration since UTC midnight 1970 January 01.
     pub fn from_unix_duration(duration: Duration) -> Self {
@@ -630,13 +948,88 @@ impl Epoch {
             .parse(s_in)
     }
 
-    fn delta_et_tai(seconds: f64) -> f64 {
-        // Calculate M, the mean anomaly.4
-        let m = NAIF_M0 + seconds * NAIF_M1;
-        // Calculate eccentric anomaly
-        let e = m + NAIF_EB * m.sin();
+    /// Returns the epoch where TT, TCG and TCB coincide (1977-01-01T00:00:32.184 TT) as a duration since the TT reference epoch.
+    const fn tcg_tcb_t0_tt() -> Duration {
+        Duration {
+            centuries: 0,
+            nanoseconds: IAU_1977_REF_EPOCH.duration.nanoseconds
+                + TT_OFFSET_MS as u64 * NANOSECONDS_PER_MILLISECOND,
+        }
+    }
 
-        (TT_OFFSET_MS * Unit::Millisecond).to_seconds() + NAIF_K * e.sin()
+    /// Returns true if conversions from or into the provided time scale depend on the leap seconds, including UT1 which otherwise matches UTC.
+    #[cfg(feature = "std")]
+    const fn depends_on_leap_seconds(ts: TimeScale) -> bool {
+        ts.uses_leap_seconds() || matches!(ts, TimeScale::UT1)
+    }
+
+    /// Returns the provided TAI - UTC in seconds as a Duration, rounded to the nanosecond since it drifted by fractions of a second before 1972.
+    fn delta_at_duration(delta_at_s: f64) -> Duration {
+        Duration::from_total_nanoseconds((delta_at_s * 1e9).round() as i128)
+    }
+
+    /// Returns TAI - UT1 at the provided TAI duration past J1900 using the installed UT1 provider (requires the `std` feature).
+    /// If no provider is installed or if it lacks data at that time, UT1 is assumed to match UTC with the provided leap seconds.
+    fn delta_tai_minus_ut1<L: LeapSecondTable>(tai_duration: Duration, provider: L) -> Duration {
+        #[cfg(feature = "std")]
+        {
+            if let Some(delta) = ut1::installed_delta_tai_minus_ut1(tai_duration) {
+                return delta;
+            }
+        }
+        Self::delta_at_duration(
+            Self::from_tai_duration(tai_duration)
+                .leap_seconds_with(false, provider)
+                .unwrap_or(0.0),
+        )
+    }
+
+    /// Returns LTC - TT at the provided TT duration past J1900, such that both coincide on 1977-01-01T00:00:32.184 TT.
+    ///
+    /// ## Accuracy
+    /// This model includes the secular rate of 56.02 µs/day and the main periodic term (0.478 µs amplitude over the anomalistic month)
+    /// of Ashby & Patla (2024). The periodic term is an approximation: it uses a Keplerian orbit with the mean eccentricity of the Moon,
+    /// whereas the solar perturbations (mainly the evection) make the eccentricity vary between about 0.026 and 0.077,
+    /// so the modeled periodic term may be off by up to 0.2 µs. The solar tidal terms are below a nanosecond and are neglected.
+    /// Hence, it should be within a quarter of a microsecond of the full relativistic model, but it is _not_ an official realization of lunar time.
+    fn delta_ltc_tt(tt_duration: Duration) -> Duration {
+        let t0 = Self::tcg_tcb_t0_tt();
+        let secular = (tt_duration - t0).to_seconds() * LTC_TT_RATE;
+        let periodic = Self::ltc_periodic_term(tt_duration) - Self::ltc_periodic_term(t0);
+        (secular + periodic) * Unit::Second
+    }
+
+    /// Returns the periodic term of LTC - TT, in seconds, using the mean anomaly of the Moon.
+    fn ltc_periodic_term(tt_duration: Duration) -> f64 {
+        let days_j2000 = (tt_duration - Unit::Second * ET_EPOCH_S).to_unit(Unit::Day);
+        let mean_anomaly = (134.963 + 13.064_993 * days_j2000).to_radians();
+        let eccentric_anomaly = mean_anomaly + LUNAR_ECCENTRICITY * mean_anomaly.sin();
+        -LTC_PERIODIC_AMPLITUDE_S * eccentric_anomaly.sin()
+    }
+
+    /// Returns the areocentric solar longitude L_s and the equation of center of Mars, both in degrees, as per Allison & McEwen (2000), also implemented in NASA GISS Mars24.
+    fn mars_solar_longitude_and_center(&self) -> (f64, f64) {
+        let days_j2000 = self.to_tt_since_j2k().to_unit(Unit::Day);
+        let mean_anomaly = (19.3871 + 0.524_020_73 * days_j2000).to_radians();
+        let fictitious_mean_sun = 270.3871 + 0.524_038_496 * days_j2000;
+        let perturbations: f64 = MARS_PERTURBATIONS
+            .iter()
+            .map(|(amplitude, period, phase)| {
+                amplitude * (0.985_626 * days_j2000 / period + phase).to_radians().cos()
+            })
+            .sum();
+        let center = (10.691 + 3.0e-7 * days_j2000) * mean_anomaly.sin()
+            + 0.623 * (2.0 * mean_anomaly).sin()
+            + 0.050 * (3.0 * mean_anomaly).sin()
+            + 0.005 * (4.0 * mean_anomaly).sin()
+            + 0.000_5 * (5.0 * mean_anomaly).sin()
+            + perturbations;
+        (fictitious_mean_sun + center, center)
+    }
+
+    /// Converts a fraction of a sol into a time of day on a 24 hour Martian clock.
+    fn mars_clock(sols: f64) -> Duration {
+        Unit::Hour * (24.0 * rem_euclid_f64(sols, 1.0))
     }
 
     fn inner_g(seconds: f64) -> f64 {
@@ -697,8 +1090,43 @@ impl Epoch {
     ///
     /// # Why does this function return an `Option` when the other returns a value
     /// This is to match the `iauDat` function of SOFA (src/dat.c). That function will return a warning and give up if the start date is before 1960.
+    ///
+    /// The installed leap seconds are used if any, cf. [install_provider](crate::leap_seconds::install_provider), and the built-in ones otherwise.
     pub fn leap_seconds(&self, iers_only: bool) -> Option<f64> {
-        self.leap_seconds_with(iers_only, LatestLeapSeconds::default())
+        #[cfg(feature = "std")]
+        {
+            if let Some(installed) = leap_seconds::installed_leap_seconds() {
+                return self.leap_seconds_with(iers_only, LeapSecondSlice::installed(&installed));
+            }
+        }
+        self.leap_seconds_with(iers_only, LeapSecondSlice::latest())
+    }
+
+    #[must_use]
+    /// Returns true if this epoch is within a leap second, i.e. at `23:59:60` in UTC, using the installed or built-in leap seconds.
+    ///
+    /// An Epoch in UTC is never within a leap second since it stores the reading of UTC, which is that of the following second during a leap second:
+    /// build such an instant with [Epoch::from_gregorian_utc] and a second of 60, or from any other time scale.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, Unit};
+    ///
+    /// let leap_second = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    /// assert!(leap_second.is_within_leap_second());
+    /// assert_eq!(leap_second.to_gregorian_utc(), (2016, 12, 31, 23, 59, 60, 500_000_000));
+    /// assert_eq!(
+    ///     leap_second + Unit::Millisecond * 500,
+    ///     Epoch::from_gregorian_utc_at_midnight(2017, 1, 1)
+    /// );
+    /// ```
+    pub fn is_within_leap_second(&self) -> bool {
+        if self.time_scale == TimeScale::UTC {
+            return false;
+        }
+        // Within a leap second, the UTC reading has already reached the following midnight but TAI - UTC has not changed yet.
+        let utc = self.to_time_scale(TimeScale::UTC);
+        utc.leap_seconds(true).unwrap_or(0.0) > self.leap_seconds(true).unwrap_or(0.0)
     }
 
     #[cfg(feature = "std")]
@@ -1021,6 +1449,68 @@ impl Epoch {
         self.to_nanoseconds_in_time_scale(TimeScale::BDT)
     }
 
+    #[must_use]
+    /// Returns seconds past IRNWT (IRNSS/NavIC) Time Epoch
+    pub fn to_irnwt_seconds(&self) -> f64 {
+        self.to_irnwt_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns `Duration` past IRNWT (IRNSS/NavIC) Time Epoch.
+    pub fn to_irnwt_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::IRNWT).duration
+    }
+
+    /// Returns nanoseconds past IRNWT (IRNSS/NavIC) Time Epoch, starting on August 22nd 1999 Midnight UTC.
+    /// NOTE: This function will return an error if the centuries past IRNWT time are not zero.
+    pub fn to_irnwt_nanoseconds(&self) -> Result<u64, HifitimeError> {
+        self.to_nanoseconds_in_time_scale(TimeScale::IRNWT)
+    }
+
+    #[must_use]
+    /// Returns days past IRNWT (IRNSS/NavIC) Time Epoch, starting on August 22nd 1999 Midnight UTC.
+    pub fn to_irnwt_days(&self) -> f64 {
+        self.to_irnwt_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns seconds past GLONASST Time Epoch, 1996 January 01 at midnight (GLONASST)
+    pub fn to_glonasst_seconds(&self) -> f64 {
+        self.to_glonasst_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns `Duration` past GLONASST Time Epoch, 1996 January 01 at midnight (GLONASST)
+    pub fn to_glonasst_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::GLONASST).duration
+    }
+
+    #[must_use]
+    /// Returns days past GLONASST Time Epoch, 1996 January 01 at midnight (GLONASST)
+    pub fn to_glonasst_days(&self) -> f64 {
+        self.to_glonasst_duration().to_unit(Unit::Day)
+    }
+
+    /// Returns nanoseconds past GLONASST Time Epoch, 1996 January 01 at midnight (GLONASST)
+    /// NOTE: This function will return an error if the centuries past GLONASST time are not zero.
+    pub fn to_glonasst_nanoseconds(&self) -> Result<u64, HifitimeError> {
+        self.to_nanoseconds_in_time_scale(TimeScale::GLONASST)
+    }
+
+    #[must_use]
+    /// Returns the GLONASS representation of this epoch: the four-year interval number N4 (1 for 1996 to 1999),
+    /// the calendar day number NT within that interval (1 to 1461), and the time of day in GLONASST.
+    pub fn to_glonasst_n4_nt(&self) -> (i32, u16, Duration) {
+        let nanoseconds = self.to_glonasst_duration().total_nanoseconds();
+        let days = nanoseconds.div_euclid(i128::from(NANOSECONDS_PER_DAY));
+        let time_of_day = Duration::from_total_nanoseconds(
+            nanoseconds.rem_euclid(i128::from(NANOSECONDS_PER_DAY)),
+        );
+        let n4 = days.div_euclid(GLONASST_DAYS_PER_FOUR_YEARS) + 1;
+        let nt = days.rem_euclid(GLONASST_DAYS_PER_FOUR_YEARS) + 1;
+        (n4 as i32, nt as u16, time_of_day)
+    }
+
     #[allow(clippy::wrong_self_convention)]
     #[must_use]
     /// Returns the Duration since the UNIX epoch UTC midnight 01 Jan 1970.
@@ -1127,6 +1617,122 @@ impl Epoch {
         self.to_jde_tdb_duration().to_unit(Unit::Day)
     }
 
+    #[must_use]
+    /// Returns the Geocentric Coordinate Time (TCG) as a Duration past the TAI epoch
+    pub fn to_tcg_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::TCG).duration
+    }
+
+    #[must_use]
+    /// Returns the Geocentric Coordinate Time (TCG) seconds past the TAI epoch
+    pub fn to_tcg_seconds(&self) -> f64 {
+        self.to_tcg_duration().to_seconds()
+    }
+
+    #[must_use]
+    pub fn to_jde_tcg_duration(&self) -> Duration {
+        self.to_tcg_duration() + Unit::Day * (MJD_J1900 + MJD_OFFSET)
+    }
+
+    #[must_use]
+    /// Returns the Julian days in Geocentric Coordinate Time (TCG)
+    pub fn to_jde_tcg_days(&self) -> f64 {
+        self.to_jde_tcg_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    pub fn to_mjd_tcg_duration(&self) -> Duration {
+        self.to_tcg_duration() + Unit::Day * MJD_J1900
+    }
+
+    #[must_use]
+    /// Returns the Modified Julian days in Geocentric Coordinate Time (TCG)
+    pub fn to_mjd_tcg_days(&self) -> f64 {
+        self.to_mjd_tcg_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the Coordinated Lunar Time (LTC) as a Duration past the TAI epoch
+    pub fn to_ltc_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::LTC).duration
+    }
+
+    #[must_use]
+    /// Returns the Coordinated Lunar Time (LTC) seconds past the TAI epoch
+    pub fn to_ltc_seconds(&self) -> f64 {
+        self.to_ltc_duration().to_seconds()
+    }
+
+    #[must_use]
+    /// Returns the Barycentric Coordinate Time (TCB) as a Duration since J2000
+    pub fn to_tcb_duration(&self) -> Duration {
+        self.to_time_scale(TimeScale::TCB).duration
+    }
+
+    #[must_use]
+    /// Returns the Barycentric Coordinate Time (TCB) seconds since J2000
+    pub fn to_tcb_seconds(&self) -> f64 {
+        self.to_tcb_duration().to_seconds()
+    }
+
+    #[must_use]
+    pub fn to_jde_tcb_duration(&self) -> Duration {
+        self.to_tcb_duration() + Unit::Day * JD_J2000
+    }
+
+    #[must_use]
+    /// Returns the Julian days in Barycentric Coordinate Time (TCB)
+    pub fn to_jde_tcb_days(&self) -> f64 {
+        self.to_jde_tcb_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    pub fn to_mjd_tcb_duration(&self) -> Duration {
+        self.to_tcb_duration() + Unit::Day * MJD_J2000
+    }
+
+    #[must_use]
+    /// Returns the Modified Julian days in Barycentric Coordinate Time (TCB)
+    pub fn to_mjd_tcb_days(&self) -> f64 {
+        self.to_mjd_tcb_duration().to_unit(Unit::Day)
+    }
+
+    #[must_use]
+    /// Returns the Mars Sol Date (MSD), i.e. the number of sols since 1873 December 29 at noon, as defined by Allison & McEwen (2000).
+    pub fn to_mars_sol_date(&self) -> f64 {
+        (self.to_tt_since_j2k().to_unit(Unit::Day) - MARS_SOL_DATE_REF_DAYS_J2000)
+            / MARS_DAYS_PER_SOL
+            + MARS_SOL_DATE_REF
+    }
+
+    #[must_use]
+    /// Returns the Mars Coordinated Time (MTC), i.e. the mean solar time at the Martian prime meridian (Airy-0).
+    ///
+    /// The returned duration is the time of day on a 24 hour Martian clock: each Martian hour is 1/24th of a sol (cf. [`Duration::SOL`]).
+    pub fn to_mars_coordinated_time(&self) -> Duration {
+        Self::mars_clock(self.to_mars_sol_date())
+    }
+
+    #[must_use]
+    /// Returns the Local Mean Solar Time (LMST) at the provided planetocentric east longitude on Mars, in degrees, on a 24 hour Martian clock.
+    pub fn to_mars_local_mean_solar_time(&self, east_longitude_deg: f64) -> Duration {
+        Self::mars_clock(self.to_mars_sol_date() + east_longitude_deg / 360.0)
+    }
+
+    #[must_use]
+    /// Returns the Local True Solar Time (LTST) at the provided planetocentric east longitude on Mars, in degrees, on a 24 hour Martian clock.
+    ///
+    /// This is the Local Mean Solar Time corrected by the Martian equation of time, i.e. the time shown by a sundial.
+    pub fn to_mars_local_true_solar_time(&self, east_longitude_deg: f64) -> Duration {
+        let (solar_longitude, center) = self.mars_solar_longitude_and_center();
+        let ls = solar_longitude.to_radians();
+        let equation_of_time_deg =
+            2.861 * (2.0 * ls).sin() - 0.071 * (4.0 * ls).sin() + 0.002 * (6.0 * ls).sin() - center;
+        Self::mars_clock(
+            self.to_mars_sol_date() + (east_longitude_deg + equation_of_time_deg) / 360.0,
+        )
+    }
+
     #[must_use]
     /// Returns the number of days since Dynamic Barycentric Time (TDB) J2000 (used for Archinal et al. rotations)
     pub fn to_tdb_days_since_j2000(&self) -> f64 {
@@ -1252,6 +1858,7 @@ impl FromStr for Epoch {
     /// assert!(Epoch::from_str("MJD 51544.5 TAI").is_ok());
     /// assert!(Epoch::from_str("SEC 0.5 TAI").is_ok());
     /// assert!(Epoch::from_str("SEC 66312032.18493909 TDB").is_ok());
+    /// assert!(Epoch::from_str("MJD 51544.5 TCB").is_ok());
     /// ```
     fn from_str(s_in: &str) -> Result<Self, Self::Err> {
         let s = s_in.trim();
@@ -1298,6 +1905,9 @@ impl FromStr for Epoch {
                     TimeScale::TAI => Ok(Self::from_jde_tai(value)),
                     TimeScale::TDB => Ok(Self::from_jde_tdb(value)),
                     TimeScale::UTC => Ok(Self::from_jde_utc(value)),
+                    TimeScale::TCG => Ok(Self::from_jde_tcg(value)),
+                    TimeScale::TCB => Ok(Self::from_jde_tcb(value)),
+                    TimeScale::UT1 => Ok(Self::from_jde_in_time_scale(value, ts)),
                     _ => Err(HifitimeError::Parse {
                         source: ParsingError::UnsupportedTimeSystem,
                         details: "for Julian Date",
@@ -1305,9 +1915,13 @@ impl FromStr for Epoch {
                 },
                 "MJD" => match ts {
                     TimeScale::TAI => Ok(Self::from_mjd_tai(value)),
-                    TimeScale::UTC | TimeScale::GPST | TimeScale::BDT | TimeScale::GST => {
-                        Ok(Self::from_mjd_in_time_scale(value, ts))
-                    }
+                    TimeScale::UTC
+                    | TimeScale::GPST
+                    | TimeScale::BDT
+                    | TimeScale::GST
+                    | TimeScale::UT1 => Ok(Self::from_mjd_in_time_scale(value, ts)),
+                    TimeScale::TCG => Ok(Self::from_mjd_tcg(value)),
+                    TimeScale::TCB => Ok(Self::from_mjd_tcb(value)),
                     _ => Err(HifitimeError::Parse {
                         source: ParsingError::UnsupportedTimeSystem,
                         details: "for Modified Julian Date",
@@ -1318,6 +1932,8 @@ impl FromStr for Epoch {
                     TimeScale::ET => Ok(Self::from_et_seconds(value)),
                     TimeScale::TDB => Ok(Self::from_tdb_seconds(value)),
                     TimeScale::TT => Ok(Self::from_tt_seconds(value)),
+                    TimeScale::TCG => Ok(Self::from_tcg_seconds(value)),
+                    TimeScale::TCB => Ok(Self::from_tcb_seconds(value)),
                     ts => {
                         let secs = value * Unit::Second;
                         Ok(Self::from_duration(secs, ts))
@@ -131,6 +131,48 @@ impl Epoch {
         Self::from_jde_tdb(days)
     }
 
+    #[classmethod]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) seconds since 1900 January 01 at midnight
+    fn init_from_tcg_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_tcg_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) duration since 1900 January 01 at midnight
+    fn init_from_tcg_duration(_cls: &Bound<'_, PyType>, duration: Duration) -> Self {
+        Self::from_tcg_duration(duration)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) seconds past 2000 JAN 01 noon (J2000 reference)
+    fn init_from_tcb_seconds(_cls: &Bound<'_, PyType>, seconds_j2000: f64) -> Self {
+        Self::from_tcb_seconds(seconds_j2000)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) duration past 2000 JAN 01 noon (J2000 reference)
+    fn init_from_tcb_duration(_cls: &Bound<'_, PyType>, duration_since_j2000: Duration) -> Self {
+        Self::from_tcb_duration(duration_since_j2000)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Geocentric Coordinate Time (TCG) in JD days
+    fn init_from_jde_tcg(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_jde_tcg(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Barycentric Coordinate Time (TCB) in JD days
+    fn init_from_jde_tcb(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_jde_tcb(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the provided Mars Sol Date (MSD)
+    fn init_from_mars_sol_date(_cls: &Bound<'_, PyType>, msd: f64) -> Self {
+        Self::from_mars_sol_date(msd)
+    }
+
     #[classmethod]
     /// Initialize an Epoch from the number of seconds since the GPS Time Epoch,
     /// defined as UTC midnight of January 5th to 6th 1980 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS#GPS_Time_.28GPST.29>).
@@ -200,6 +242,27 @@ impl Epoch {
         Self::from_gst_nanoseconds(nanoseconds)
     }
 
+    #[classmethod]
+    /// Initialize an Epoch from the number of seconds since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    fn init_from_irnwt_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_irnwt_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of days since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    fn init_from_irnwt_days(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_irnwt_days(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of nanoseconds since the IRNWT (IRNSS/NavIC) Time Epoch,
+    /// starting August 22nd 1999 midnight (UTC), aligned with the GST Time Epoch.
+    fn init_from_irnwt_nanoseconds(_cls: &Bound<'_, PyType>, nanoseconds: u64) -> Self {
+        Self::from_irnwt_nanoseconds(nanoseconds)
+    }
+
     #[classmethod]
     /// Initialize an Epoch from the number of seconds since the BeiDou Time Epoch,
     /// defined as January 1st 2006 (cf. <https://gssc.esa.int/navipedia/index.php/Time_References_in_GNSS>).
@@ -222,6 +285,38 @@ impl Epoch {
         Self::from_bdt_nanoseconds(nanoseconds)
     }
 
+    #[classmethod]
+    /// Initialize an Epoch from the number of seconds since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    fn init_from_glonasst_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
+        Self::from_glonasst_seconds(seconds)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of days since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    fn init_from_glonasst_days(_cls: &Bound<'_, PyType>, days: f64) -> Self {
+        Self::from_glonasst_days(days)
+    }
+
+    #[classmethod]
+    /// Initialize an Epoch from the number of nanoseconds since the GLONASST Time Epoch,
+    /// starting on January 1st 1996 at midnight in Moscow time, i.e. 1995 December 31st 21:00 UTC.
+    fn init_from_glonasst_nanoseconds(_cls: &Bound<'_, PyType>, nanoseconds: u64) -> Self {
+        Self::from_glonasst_nanoseconds(nanoseconds)
+    }
+
+    #[classmethod]
+    /// Builds a GLONASST Epoch from the four-year interval number N4, the day number NT in that interval, and the time of day.
+    fn init_from_glonasst_n4_nt(
+        _cls: &Bound<'_, PyType>,
+        n4: i32,
+        nt: u16,
+        time_of_day: Duration,
+    ) -> Self {
+        Self::from_glonasst_n4_nt(n4, nt, time_of_day)
+    }
+
     #[classmethod]
     /// Initialize an Epoch from the provided UNIX second timestamp since UTC midnight 1970 January 01.
     fn init_from_unix_seconds(_cls: &Bound<'_, PyType>, seconds: f64) -> Self {
@@ -449,7 +544,7 @@ impl Epoch {
         }
     }
 
-    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondProvider.
+    /// Get the accumulated number of leap seconds up to this Epoch from the provided LeapSecondTable.
     /// Returns None if the epoch is before 1960, year at which UTC was defined.
     ///
     /// # Why does this function return an `Option` when the other returns a value
@@ -0,0 +1,172 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Minimal SHA-1 (FIPS 180-1), only used to verify the hash of the IETF leap seconds file, including in const contexts. This is _not_ meant for any security purpose.
+
+/// Returns the SHA-1 digest of the provided message as five 32-bit words.
+#[cfg(feature = "std")]
+pub(crate) const fn sha1(message: &[u8]) -> [u32; 5] {
+    let mut hasher = Sha1::new();
+    hasher.update(message);
+    hasher.finish()
+}
+
+/// SHA-1 of a message provided in parts, which works in const contexts and without allocation.
+pub(crate) struct Sha1 {
+    h: [u32; 5],
+    block: [u8; 64],
+    len: u64,
+}
+
+impl Sha1 {
+    pub(crate) const fn new() -> Self {
+        Self {
+            h: [
+                0x6745_2301,
+                0xEFCD_AB89,
+                0x98BA_DCFE,
+                0x1032_5476,
+                0xC3D2_E1F0,
+            ],
+            block: [0; 64],
+            len: 0,
+        }
+    }
+
+    /// Appends the provided bytes to the message.
+    pub(crate) const fn update(&mut self, bytes: &[u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    const fn push(&mut self, byte: u8) {
+        self.block[(self.len % 64) as usize] = byte;
+        self.len += 1;
+        if self.len.is_multiple_of(64) {
+            self.h = compress(self.h, &self.block);
+        }
+    }
+
+    /// Returns the digest of the message as five 32-bit words.
+    pub(crate) const fn finish(mut self) -> [u32; 5] {
+        // Pad the message with a one bit, zeros, and its length in bits, to a multiple of 64 bytes.
+        let bit_len = self.len * 8;
+        self.push(0x80);
+        while self.len % 64 != 56 {
+            self.push(0);
+        }
+        self.update(&bit_len.to_be_bytes());
+        self.h
+    }
+}
+
+/// Processes a block of 64 bytes of the message.
+const fn compress(h: [u32; 5], block: &[u8; 64]) -> [u32; 5] {
+    let mut w = [0_u32; 80];
+    let mut i = 0;
+    while i < 16 {
+        w[i] = u32::from_be_bytes([
+            block[4 * i],
+            block[4 * i + 1],
+            block[4 * i + 2],
+            block[4 * i + 3],
+        ]);
+        i += 1;
+    }
+    while i < 80 {
+        w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = h;
+    i = 0;
+    while i < 80 {
+        let (f, k) = match i {
+            0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+            20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+            _ => (b ^ c ^ d, 0xCA62_C1D6),
+        };
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(w[i]);
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+        i += 1;
+    }
+
+    [
+        h[0].wrapping_add(a),
+        h[1].wrapping_add(b),
+        h[2].wrapping_add(c),
+        h[3].wrapping_add(d),
+        h[4].wrapping_add(e),
+    ]
+}
+
+#[cfg(all(test, feature = "std"))]
+mod ut_sha1 {
+    use super::sha1;
+
+    #[test]
+    fn fips_180_vectors() {
+        assert_eq!(
+            sha1(b"abc"),
+            [
+                0xA999_3E36,
+                0x4706_816A,
+                0xBA3E_2571,
+                0x7850_C26C,
+                0x9CD0_D89D
+            ]
+        );
+        assert_eq!(
+            sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            [
+                0x8498_3E44,
+                0x1C3B_D26E,
+                0xBAAE_4AA1,
+                0xF951_29E5,
+                0xE546_70F1
+            ]
+        );
+        // Spans two blocks once padded
+        const LONG: [u32; 5] = sha1(&[b'a'; 100]);
+        assert_eq!(
+            LONG,
+            [
+                0x7F90_0025,
+                0x7A49_18D7,
+                0x0726_55EA,
+                0x4685_40CD,
+                0xCBD4_2E0C
+            ]
+        );
+        assert_eq!(
+            sha1(b""),
+            [
+                0xDA39_A3EE,
+                0x5E6B_4B0D,
+                0x3255_BFEF,
+                0x9560_1890,
+                0xAFD8_0709
+            ]
+        );
+    }
+}
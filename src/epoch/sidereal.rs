@@ -0,0 +1,485 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::TAU;
+
+use super::rem_euclid_f64;
+use crate::Epoch;
+
+#[cfg(any(feature = "std", test))]
+use crate::{Duration, J2000_REF_EPOCH, NANOSECONDS_PER_DAY, SECONDS_PER_DAY};
+
+#[cfg(feature = "std")]
+use super::ut1::Ut1Provider;
+#[cfg(feature = "std")]
+use crate::HifitimeError;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+/// Arcseconds to radians
+const ARCSEC_TO_RAD: f64 = TAU / 1_296_000.0;
+
+/// Units of 0.1 microarcsecond to radians
+const DECI_MICRO_ARCSEC_TO_RAD: f64 = ARCSEC_TO_RAD / 1e7;
+
+/// Luni-solar terms of the IAU 2000B nutation in longitude, from SOFA `iauNut00b`, as the multipliers of the
+/// Delaunay arguments (l, l', F, D, Ω) and the coefficients of sin, t sin, and cos in units of 0.1 microarcsecond.
+const NUTATION_LONGITUDE: [([i8; 5], f64, f64, f64); 77] = [
+    ([0, 0, 0, 0, 1], -172_064_161.0, -174_666.0, 33_386.0),
+    ([0, 0, 2, -2, 2], -13_170_906.0, -1_675.0, -13_696.0),
+    ([0, 0, 2, 0, 2], -2_276_413.0, -234.0, 2_796.0),
+    ([0, 0, 0, 0, 2], 2_074_554.0, 207.0, -698.0),
+    ([0, 1, 0, 0, 0], 1_475_877.0, -3_633.0, 11_817.0),
+    ([0, 1, 2, -2, 2], -516_821.0, 1_226.0, -524.0),
+    ([1, 0, 0, 0, 0], 711_159.0, 73.0, -872.0),
+    ([0, 0, 2, 0, 1], -387_298.0, -367.0, 380.0),
+    ([1, 0, 2, 0, 2], -301_461.0, -36.0, 816.0),
+    ([0, -1, 2, -2, 2], 215_829.0, -494.0, 111.0),
+    ([0, 0, 2, -2, 1], 128_227.0, 137.0, 181.0),
+    ([-1, 0, 2, 0, 2], 123_457.0, 11.0, 19.0),
+    ([-1, 0, 0, 2, 0], 156_994.0, 10.0, -168.0),
+    ([1, 0, 0, 0, 1], 63_110.0, 63.0, 27.0),
+    ([-1, 0, 0, 0, 1], -57_976.0, -63.0, -189.0),
+    ([-1, 0, 2, 2, 2], -59_641.0, -11.0, 149.0),
+    ([1, 0, 2, 0, 1], -51_613.0, -42.0, 129.0),
+    ([-2, 0, 2, 0, 1], 45_893.0, 50.0, 31.0),
+    ([0, 0, 0, 2, 0], 63_384.0, 11.0, -150.0),
+    ([0, 0, 2, 2, 2], -38_571.0, -1.0, 158.0),
+    ([0, -2, 2, -2, 2], 32_481.0, 0.0, 0.0),
+    ([-2, 0, 0, 2, 0], -47_722.0, 0.0, -18.0),
+    ([2, 0, 2, 0, 2], -31_046.0, -1.0, 131.0),
+    ([1, 0, 2, -2, 2], 28_593.0, 0.0, -1.0),
+    ([-1, 0, 2, 0, 1], 20_441.0, 21.0, 10.0),
+    ([2, 0, 0, 0, 0], 29_243.0, 0.0, -74.0),
+    ([0, 0, 2, 0, 0], 25_887.0, 0.0, -66.0),
+    ([0, 1, 0, 0, 1], -14_053.0, -25.0, 79.0),
+    ([-1, 0, 0, 2, 1], 15_164.0, 10.0, 11.0),
+    ([0, 2, 2, -2, 2], -15_794.0, 72.0, -16.0),
+    ([0, 0, -2, 2, 0], 21_783.0, 0.0, 13.0),
+    ([1, 0, 0, -2, 1], -12_873.0, -10.0, -37.0),
+    ([0, -1, 0, 0, 1], -12_654.0, 11.0, 63.0),
+    ([-1, 0, 2, 2, 1], -10_204.0, 0.0, 25.0),
+    ([0, 2, 0, 0, 0], 16_707.0, -85.0, -10.0),
+    ([1, 0, 2, 2, 2], -7_691.0, 0.0, 44.0),
+    ([-2, 0, 2, 0, 0], -11_024.0, 0.0, -14.0),
+    ([0, 1, 2, 0, 2], 7_566.0, -21.0, -11.0),
+    ([0, 0, 2, 2, 1], -6_637.0, -11.0, 25.0),
+    ([0, -1, 2, 0, 2], -7_141.0, 21.0, 8.0),
+    ([0, 0, 0, 2, 1], -6_302.0, -11.0, 2.0),
+    ([1, 0, 2, -2, 1], 5_800.0, 10.0, 2.0),
+    ([2, 0, 2, -2, 2], 6_443.0, 0.0, -7.0),
+    ([-2, 0, 0, 2, 1], -5_774.0, -11.0, -15.0),
+    ([2, 0, 2, 0, 1], -5_350.0, 0.0, 21.0),
+    ([0, -1, 2, -2, 1], -4_752.0, -11.0, -3.0),
+    ([0, 0, 0, -2, 1], -4_940.0, -11.0, -21.0),
+    ([-1, -1, 0, 2, 0], 7_350.0, 0.0, -8.0),
+    ([2, 0, 0, -2, 1], 4_065.0, 0.0, 6.0),
+    ([1, 0, 0, 2, 0], 6_579.0, 0.0, -24.0),
+    ([0, 1, 2, -2, 1], 3_579.0, 0.0, 5.0),
+    ([1, -1, 0, 0, 0], 4_725.0, 0.0, -6.0),
+    ([-2, 0, 2, 0, 2], -3_075.0, 0.0, -2.0),
+    ([3, 0, 2, 0, 2], -2_904.0, 0.0, 15.0),
+    ([0, -1, 0, 2, 0], 4_348.0, 0.0, -10.0),
+    ([1, -1, 2, 0, 2], -2_878.0, 0.0, 8.0),
+    ([0, 0, 0, 1, 0], -4_230.0, 0.0, 5.0),
+    ([-1, -1, 2, 2, 2], -2_819.0, 0.0, 7.0),
+    ([-1, 0, 2, 0, 0], -4_056.0, 0.0, 5.0),
+    ([0, -1, 2, 2, 2], -2_647.0, 0.0, 11.0),
+    ([-2, 0, 0, 0, 1], -2_294.0, 0.0, -10.0),
+    ([1, 1, 2, 0, 2], 2_481.0, 0.0, -7.0),
+    ([2, 0, 0, 0, 1], 2_179.0, 0.0, -2.0),
+    ([-1, 1, 0, 1, 0], 3_276.0, 0.0, 1.0),
+    ([1, 1, 0, 0, 0], -3_389.0, 0.0, 5.0),
+    ([1, 0, 2, 0, 0], 3_339.0, 0.0, -13.0),
+    ([-1, 0, 2, -2, 1], -1_987.0, 0.0, -6.0),
+    ([1, 0, 0, 0, 2], -1_981.0, 0.0, 0.0),
+    ([-1, 0, 0, 1, 0], 4_026.0, 0.0, -353.0),
+    ([0, 0, 2, 1, 2], 1_660.0, 0.0, -5.0),
+    ([-1, 0, 2, 4, 2], -1_521.0, 0.0, 9.0),
+    ([-1, 1, 0, 1, 1], 1_314.0, 0.0, 0.0),
+    ([0, -2, 2, -2, 1], -1_283.0, 0.0, 0.0),
+    ([1, 0, 2, 2, 1], -1_331.0, 0.0, 8.0),
+    ([-2, 0, 2, 2, 2], 1_383.0, 0.0, -2.0),
+    ([-1, 0, 0, 0, 2], 1_405.0, 0.0, 4.0),
+    ([1, 1, 2, -2, 2], 1_290.0, 0.0, 0.0),
+];
+
+/// Fixed offset of the IAU 2000B nutation in longitude in lieu of the planetary terms, in radians.
+const NUTATION_LONGITUDE_PLANETARY_OFFSET: f64 = -0.135e-3 * ARCSEC_TO_RAD;
+
+/// Complementary terms of the equation of the equinoxes, from SOFA `iauEect00`, as the multipliers of the fundamental
+/// arguments (l, l', F, D, Ω, L_Ve, L_E, p_A) and the coefficients of sin and cos in arcseconds.
+const EQUINOXES_COMPLEMENTARY: [([i8; 8], f64, f64); 33] = [
+    ([0, 0, 0, 0, 1, 0, 0, 0], 2640.96e-6, -0.39e-6),
+    ([0, 0, 0, 0, 2, 0, 0, 0], 63.52e-6, -0.02e-6),
+    ([0, 0, 2, -2, 3, 0, 0, 0], 11.75e-6, 0.01e-6),
+    ([0, 0, 2, -2, 1, 0, 0, 0], 11.21e-6, 0.01e-6),
+    ([0, 0, 2, -2, 2, 0, 0, 0], -4.55e-6, 0.0),
+    ([0, 0, 2, 0, 3, 0, 0, 0], 2.02e-6, 0.0),
+    ([0, 0, 2, 0, 1, 0, 0, 0], 1.98e-6, 0.0),
+    ([0, 0, 0, 0, 3, 0, 0, 0], -1.72e-6, 0.0),
+    ([0, 1, 0, 0, 1, 0, 0, 0], -1.41e-6, -0.01e-6),
+    ([0, 1, 0, 0, -1, 0, 0, 0], -1.26e-6, -0.01e-6),
+    ([1, 0, 0, 0, -1, 0, 0, 0], -0.63e-6, 0.0),
+    ([1, 0, 0, 0, 1, 0, 0, 0], -0.63e-6, 0.0),
+    ([0, 1, 2, -2, 3, 0, 0, 0], 0.46e-6, 0.0),
+    ([0, 1, 2, -2, 1, 0, 0, 0], 0.45e-6, 0.0),
+    ([0, 0, 4, -4, 4, 0, 0, 0], 0.36e-6, 0.0),
+    ([0, 0, 1, -1, 1, -8, 12, 0], -0.24e-6, -0.12e-6),
+    ([0, 0, 2, 0, 0, 0, 0, 0], 0.32e-6, 0.0),
+    ([0, 0, 2, 0, 2, 0, 0, 0], 0.28e-6, 0.0),
+    ([1, 0, 2, 0, 3, 0, 0, 0], 0.27e-6, 0.0),
+    ([1, 0, 2, 0, 1, 0, 0, 0], 0.26e-6, 0.0),
+    ([0, 0, 2, -2, 0, 0, 0, 0], -0.21e-6, 0.0),
+    ([0, 1, -2, 2, -3, 0, 0, 0], 0.19e-6, 0.0),
+    ([0, 1, -2, 2, -1, 0, 0, 0], 0.18e-6, 0.0),
+    ([0, 0, 0, 0, 0, 8, -13, -1], -0.10e-6, 0.05e-6),
+    ([0, 0, 0, 2, 0, 0, 0, 0], 0.15e-6, 0.0),
+    ([2, 0, -2, 0, -1, 0, 0, 0], -0.14e-6, 0.0),
+    ([1, 0, 0, -2, 1, 0, 0, 0], 0.14e-6, 0.0),
+    ([0, 1, 2, -2, 2, 0, 0, 0], -0.14e-6, 0.0),
+    ([1, 0, 0, -2, -1, 0, 0, 0], 0.14e-6, 0.0),
+    ([0, 0, 4, -2, 4, 0, 0, 0], 0.13e-6, 0.0),
+    ([0, 0, 2, -2, 4, 0, 0, 0], -0.11e-6, 0.0),
+    ([1, 0, -2, 0, -3, 0, 0, 0], 0.11e-6, 0.0),
+    ([1, 0, -2, 0, -1, 0, 0, 0], 0.11e-6, 0.0),
+];
+
+/// Secular complementary term of the equation of the equinoxes, in arcseconds per Julian century.
+const EQUINOXES_COMPLEMENTARY_T1: f64 = -0.87e-6;
+
+/// Precession rate correction of the obliquity (IAU 2000), in arcseconds per Julian century, as in SOFA `iauPr00`.
+const OBLIQUITY_PRECESSION_CORRECTION: f64 = -0.025_24;
+
+/// Returns the Delaunay arguments (l, l', F, D, Ω) in radians at the provided TT Julian centuries since J2000, as in SOFA `iauNut00b`.
+fn delaunay_arguments(t: f64) -> [f64; 5] {
+    [
+        485_868.249_036 + 1_717_915_923.217_8 * t,
+        1_287_104.793_05 + 129_596_581.048_1 * t,
+        335_779.526_232 + 1_739_527_262.847_8 * t,
+        1_072_260.703_69 + 1_602_961_601.209_0 * t,
+        450_160.398_036 - 6_962_890.543_1 * t,
+    ]
+    .map(|arcsec| rem_euclid_f64(arcsec, 1_296_000.0) * ARCSEC_TO_RAD)
+}
+
+/// Returns the fundamental arguments (l, l', F, D, Ω, L_Ve, L_E, p_A) in radians at the provided TT Julian centuries since J2000 (IERS Conventions 2003),
+/// as SOFA `iauFal03`, `iauFalp03`, `iauFaf03`, `iauFad03`, `iauFaom03`, `iauFave03`, `iauFae03` and `iauFapa03`.
+fn fundamental_arguments(t: f64) -> [f64; 8] {
+    let delaunay = [
+        485_868.249_036
+            + t * (1_717_915_923.217_8 + t * (31.879_2 + t * (0.051_635 + t * -0.000_244_70))),
+        1_287_104.793_048
+            + t * (129_596_581.048_1 + t * (-0.553_2 + t * (0.000_136 + t * -0.000_011_49))),
+        335_779.526_232
+            + t * (1_739_527_262.847_8 + t * (-12.751_2 + t * (-0.001_037 + t * 0.000_004_17))),
+        1_072_260.703_692
+            + t * (1_602_961_601.209_0 + t * (-6.370_6 + t * (0.006_593 + t * -0.000_031_69))),
+        450_160.398_036
+            + t * (-6_962_890.543_1 + t * (7.472_2 + t * (0.007_702 + t * -0.000_059_39))),
+    ]
+    .map(|arcsec| rem_euclid_f64(arcsec, 1_296_000.0) * ARCSEC_TO_RAD);
+    [
+        delaunay[0],
+        delaunay[1],
+        delaunay[2],
+        delaunay[3],
+        delaunay[4],
+        rem_euclid_f64(3.176_146_697 + 1_021.328_554_621_1 * t, TAU),
+        rem_euclid_f64(1.753_470_314 + 628.307_584_999_1 * t, TAU),
+        (0.024_381_750 + 0.000_005_386_91 * t) * t,
+    ]
+}
+
+/// Returns the argument of the provided multipliers of the fundamental arguments.
+fn argument<const N: usize>(multipliers: &[i8; N], arguments: &[f64; N]) -> f64 {
+    multipliers
+        .iter()
+        .zip(arguments)
+        .map(|(n, arg)| f64::from(*n) * arg)
+        .sum()
+}
+
+/// Returns the UT1 Julian days since J2000 split into the fraction of day since noon and the number of days, to preserve precision.
+#[cfg(any(feature = "std", test))]
+fn ut1_days_j2k(ut1_duration: Duration) -> (f64, f64) {
+    let nanos = (ut1_duration - J2000_REF_EPOCH.duration).total_nanoseconds();
+    let fraction =
+        nanos.rem_euclid(i128::from(NANOSECONDS_PER_DAY)) as f64 / NANOSECONDS_PER_DAY as f64;
+    (fraction, nanos as f64 / NANOSECONDS_PER_DAY as f64)
+}
+
+/// Returns the Earth Rotation Angle (IAU 2000) in radians at the provided UT1 duration past J1900, as SOFA `iauEra00`.
+#[cfg(any(feature = "std", test))]
+fn earth_rotation_angle_iau2000(ut1_duration: Duration) -> f64 {
+    let (fraction, days) = ut1_days_j2k(ut1_duration);
+    rem_euclid_f64(
+        TAU * (fraction + 0.779_057_273_264 + 0.002_737_811_911_354_48 * days),
+        TAU,
+    )
+}
+
+/// Returns the Greenwich mean sidereal time (IAU 1982) in radians at the provided UT1 duration past J1900, as SOFA `iauGmst82`.
+#[cfg(any(feature = "std", test))]
+fn gmst_iau1982(ut1_duration: Duration) -> f64 {
+    let (fraction, days) = ut1_days_j2k(ut1_duration);
+    let t = days / 36_525.0;
+    // GMST at 0h UT1 in seconds, offset by half a day since the fraction of day is counted from noon.
+    let gmst_0h_s = 24_110.548_41 - SECONDS_PER_DAY / 2.0
+        + (8_640_184.812_866 + (0.093_104 - 6.2e-6 * t) * t) * t;
+    rem_euclid_f64(
+        (gmst_0h_s + fraction * SECONDS_PER_DAY) * TAU / SECONDS_PER_DAY,
+        TAU,
+    )
+}
+
+/// Returns the Greenwich mean sidereal time (IAU 2000) in radians, as SOFA `iauGmst00`.
+#[cfg(any(feature = "std", test))]
+fn gmst_iau2000(ut1_duration: Duration, tt_centuries_j2k: f64) -> f64 {
+    let t = tt_centuries_j2k;
+    let polynomial_arcsec = 0.014_506
+        + (4_612.157_399_66 + (1.396_677_21 + (-0.000_093_44 + 0.000_018_82 * t) * t) * t) * t;
+    rem_euclid_f64(
+        earth_rotation_angle_iau2000(ut1_duration) + polynomial_arcsec * ARCSEC_TO_RAD,
+        TAU,
+    )
+}
+
+/// Returns the Greenwich mean sidereal time (IAU 2006) in radians, as SOFA `iauGmst06`.
+#[cfg(any(feature = "std", test))]
+fn gmst_iau2006(ut1_duration: Duration, tt_centuries_j2k: f64) -> f64 {
+    let t = tt_centuries_j2k;
+    let polynomial_arcsec = 0.014_506
+        + (4_612.156_534
+            + (1.391_581_7 + (-0.000_000_44 + (-0.000_029_956 - 0.000_000_036_8 * t) * t) * t) * t)
+            * t;
+    rem_euclid_f64(
+        earth_rotation_angle_iau2000(ut1_duration) + polynomial_arcsec * ARCSEC_TO_RAD,
+        TAU,
+    )
+}
+
+/// Returns the nutation in longitude (IAU 2000B) in radians at the provided TT Julian centuries since J2000, as SOFA `iauNut00b`.
+fn nutation_longitude_iau2000b(tt_centuries_j2k: f64) -> f64 {
+    let t = tt_centuries_j2k;
+    let delaunay = delaunay_arguments(t);
+
+    // Summed from the smallest terms to the largest, as SOFA does.
+    NUTATION_LONGITUDE
+        .iter()
+        .rev()
+        .map(|(multipliers, sin, sin_t, cos)| {
+            let arg = rem_euclid_f64(argument(multipliers, &delaunay), TAU);
+            (sin + sin_t * t) * arg.sin() + cos * arg.cos()
+        })
+        .sum::<f64>()
+        * DECI_MICRO_ARCSEC_TO_RAD
+        + NUTATION_LONGITUDE_PLANETARY_OFFSET
+}
+
+/// Returns the complementary terms of the equation of the equinoxes in radians at the provided TT Julian centuries since J2000, as SOFA `iauEect00`.
+fn equinoxes_complementary_terms(tt_centuries_j2k: f64) -> f64 {
+    let t = tt_centuries_j2k;
+    let arguments = fundamental_arguments(t);
+
+    let periodic = EQUINOXES_COMPLEMENTARY
+        .iter()
+        .rev()
+        .map(|(multipliers, sin, cos)| {
+            let arg = argument(multipliers, &arguments);
+            sin * arg.sin() + cos * arg.cos()
+        })
+        .sum::<f64>();
+
+    (periodic + EQUINOXES_COMPLEMENTARY_T1 * arguments[4].sin() * t) * ARCSEC_TO_RAD
+}
+
+/// Returns the equation of the equinoxes (IAU 2000B) in radians at the provided TT Julian centuries since J2000, as SOFA `iauEe00b`.
+fn equation_of_the_equinoxes_iau2000b(tt_centuries_j2k: f64) -> f64 {
+    let t = tt_centuries_j2k;
+
+    // Mean obliquity of the ecliptic (IAU 1980), as SOFA `iauObl80`, with the precession rate correction of `iauPr00`
+    let obliquity = (84_381.448
+        + (-46.815_0 + (-0.000_59 + 0.001_813 * t) * t) * t
+        + OBLIQUITY_PRECESSION_CORRECTION * t)
+        * ARCSEC_TO_RAD;
+
+    nutation_longitude_iau2000b(t) * obliquity.cos() + equinoxes_complementary_terms(t)
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns the equation of the equinoxes (IAU 2000B) in radians at this epoch, i.e. the difference between the Greenwich apparent and mean sidereal times, as SOFA `iauEe00b`.
+    ///
+    /// The nutation in longitude is the full IAU 2000B model, which is within one milliarcsecond of the IAU 2000A model between 1995 and 2050.
+    pub fn equation_of_the_equinoxes(&self) -> f64 {
+        equation_of_the_equinoxes_iau2000b(self.to_tt_centuries_j2k())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Epoch {
+    /// Returns the Earth Rotation Angle (IAU 2000) in radians within [0, 2π) at this epoch, as SOFA `iauEra00`, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn earth_rotation_angle(&self, provider: &Ut1Provider) -> Result<f64, HifitimeError> {
+        Ok(earth_rotation_angle_iau2000(
+            self.try_to_ut1_duration(provider)?,
+        ))
+    }
+
+    /// Returns the Greenwich mean sidereal time (IAU 1982) in radians within [0, 2π) at this epoch, as SOFA `iauGmst82`, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn greenwich_mean_sidereal_time_iau1982(
+        &self,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(gmst_iau1982(self.try_to_ut1_duration(provider)?))
+    }
+
+    /// Returns the Greenwich mean sidereal time (IAU 2000) in radians within [0, 2π) at this epoch, as SOFA `iauGmst00`, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn greenwich_mean_sidereal_time_iau2000(
+        &self,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(gmst_iau2000(
+            self.try_to_ut1_duration(provider)?,
+            self.to_tt_centuries_j2k(),
+        ))
+    }
+
+    /// Returns the Greenwich mean sidereal time (IAU 2006) in radians within [0, 2π) at this epoch, as SOFA `iauGmst06`, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn greenwich_mean_sidereal_time_iau2006(
+        &self,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(gmst_iau2006(
+            self.try_to_ut1_duration(provider)?,
+            self.to_tt_centuries_j2k(),
+        ))
+    }
+
+    /// Returns the Greenwich apparent sidereal time (IAU 2000B) in radians within [0, 2π) at this epoch, using the provided UT1 provider.
+    ///
+    /// This is the Greenwich mean sidereal time (IAU 2000) corrected by the [equation of the equinoxes](Epoch::equation_of_the_equinoxes), as SOFA `iauGst00b`
+    /// but with TT rather than UT1 for the nutation, and is within one milliarcsecond of the IAU 2006/2000A model of SOFA `iauGst06a`.
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn greenwich_apparent_sidereal_time(
+        &self,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(rem_euclid_f64(
+            self.greenwich_mean_sidereal_time_iau2000(provider)? + self.equation_of_the_equinoxes(),
+            TAU,
+        ))
+    }
+
+    /// Returns the local mean sidereal time (IAU 2006) in radians within [0, 2π) at the provided east longitude in degrees, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn local_mean_sidereal_time(
+        &self,
+        east_longitude_deg: f64,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(rem_euclid_f64(
+            self.greenwich_mean_sidereal_time_iau2006(provider)? + east_longitude_deg.to_radians(),
+            TAU,
+        ))
+    }
+
+    /// Returns the local apparent sidereal time (IAU 2000B) in radians within [0, 2π) at the provided east longitude in degrees, using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch.
+    pub fn local_apparent_sidereal_time(
+        &self,
+        east_longitude_deg: f64,
+        provider: &Ut1Provider,
+    ) -> Result<f64, HifitimeError> {
+        Ok(rem_euclid_f64(
+            self.greenwich_apparent_sidereal_time(provider)? + east_longitude_deg.to_radians(),
+            TAU,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod ut_sidereal {
+    use super::{
+        earth_rotation_angle_iau2000, equation_of_the_equinoxes_iau2000b,
+        equinoxes_complementary_terms, gmst_iau1982, gmst_iau2000, gmst_iau2006,
+        nutation_longitude_iau2000b,
+    };
+    use crate::{Duration, Unit, J2000_REF_EPOCH};
+
+    /// Returns the duration past J1900 of the provided two-part Julian date, as used in the SOFA test cases.
+    fn jd_to_duration(jd1: f64, jd2: f64) -> Duration {
+        J2000_REF_EPOCH.duration + Unit::Day * ((jd1 - 2_451_545.0) + jd2)
+    }
+
+    #[test]
+    fn sofa_sidereal() {
+        // Test cases of SOFA `t_era00`, `t_gmst82`, `t_gmst00`, `t_gmst06`, `t_nut00b`, `t_eect00`, `t_ee00b`, `t_gst00b` and `t_gst06a`
+        let era = earth_rotation_angle_iau2000(jd_to_duration(2_454_388.0, 0.5));
+        assert!((era - 0.402_283_724_002_815_810_2).abs() < 1e-12, "{era}");
+
+        // SOFA `iauGst00b` uses UT1 as TT, and so do these test cases.
+        let ut1 = jd_to_duration(2_453_736.0, 0.5);
+        let tt_centuries = (2_453_736.5 - 2_451_545.0) / 36_525.0;
+        let gmst82 = gmst_iau1982(ut1);
+        assert!(
+            (gmst82 - 1.754_174_981_860_675_096).abs() < 1e-12,
+            "{gmst82}"
+        );
+        let gmst00 = gmst_iau2000(ut1, tt_centuries);
+        assert!(
+            (gmst00 - 1.754_174_972_210_740_592).abs() < 1e-12,
+            "{gmst00}"
+        );
+        let gmst06 = gmst_iau2006(ut1, tt_centuries);
+        assert!(
+            (gmst06 - 1.754_174_971_870_091_203).abs() < 1e-12,
+            "{gmst06}"
+        );
+
+        let dpsi = nutation_longitude_iau2000b(tt_centuries);
+        assert!(
+            (dpsi - -0.963_255_229_114_836_278_3e-5).abs() < 1e-15,
+            "{dpsi:e}"
+        );
+        let eect = equinoxes_complementary_terms(tt_centuries);
+        assert!(
+            (eect - 0.204_608_500_488_512_526_4e-8).abs() < 1e-18,
+            "{eect:e}"
+        );
+        let ee = equation_of_the_equinoxes_iau2000b(tt_centuries);
+        assert!(
+            (ee - -0.883_570_006_000_303_283_1e-5).abs() < 1e-15,
+            "{ee:e}"
+        );
+        let gst = gmst00 + ee;
+        assert!((gst - 1.754_166_136_510_680_589).abs() < 1e-12, "{gst}");
+        // The IAU 2000B model is within one milliarcsecond of the IAU 2006/2000A model.
+        assert!((gst - 1.754_166_137_675_019_159).abs() < 5e-9, "{gst}");
+
+        // Over the centuries, the equation of the equinoxes is dominated by the 18.6 year nutation of the node of the Moon.
+        for centuries in [-2.0, -1.0, 0.5, 1.0, 2.0] {
+            let ee = equation_of_the_equinoxes_iau2000b(centuries);
+            assert!(ee.abs() < 1.2e-4, "{centuries} {ee:e}");
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::leap_seconds::{LeapSecond, LeapSecondTable};
+use crate::{Duration, Epoch, TimeScale, NANOSECONDS_PER_SECOND};
+
+/// A leap second as seen by a smeared UTC time scale, in nanoseconds.
+struct SmearedLeapSecond {
+    /// UTC reading of the midnight at which this leap second ends.
+    midnight: i128,
+    /// TAI - UTC before this leap second.
+    delta_at_before: i128,
+    /// TAI - UTC after this leap second.
+    delta_at_after: i128,
+}
+
+impl SmearedLeapSecond {
+    /// Returns the provided leap seconds announced by the IERS, i.e. those used by [TimeScale::UTC].
+    fn iers(leap_seconds: &[LeapSecond]) -> impl Iterator<Item = Self> + '_ {
+        let mut delta_at_before = 0;
+        leap_seconds
+            .iter()
+            .filter(|leap_second| leap_second.announced_by_iers)
+            .map(move |leap_second| {
+                let delta_at_after = (leap_second.delta_at * NANOSECONDS_PER_SECOND as f64) as i128;
+                let me = Self {
+                    midnight: (leap_second.timestamp_tai_s * NANOSECONDS_PER_SECOND as f64) as i128,
+                    delta_at_before,
+                    delta_at_after,
+                };
+                delta_at_before = delta_at_after;
+                me
+            })
+    }
+
+    /// Returns true if this is a leap second which is smeared, as opposed to the initial TAI - UTC offset of 1972.
+    fn is_smeared(&self) -> bool {
+        (self.delta_at_after - self.delta_at_before).abs() == i128::from(NANOSECONDS_PER_SECOND)
+    }
+
+    /// Returns the smearing window of the provided time scale as (start, end, TAI start, TAI end).
+    fn window(&self, ts: TimeScale) -> (i128, i128, i128, i128) {
+        let (before, after) = smearing_window_s(ts);
+        let start = self.midnight - before * i128::from(NANOSECONDS_PER_SECOND);
+        let end = self.midnight + after * i128::from(NANOSECONDS_PER_SECOND);
+        (
+            start,
+            end,
+            start + self.delta_at_before,
+            end + self.delta_at_after,
+        )
+    }
+}
+
+/// Returns the number of seconds of smearing before and after the midnight of a leap second.
+const fn smearing_window_s(ts: TimeScale) -> (i128, i128) {
+    match ts {
+        TimeScale::UTCSLS => (1_000, 0),
+        _ => (43_200, 43_200),
+    }
+}
+
+impl Epoch {
+    /// Converts the provided TAI duration into the provided smeared UTC time scale, with the leap seconds of the provider.
+    ///
+    /// Within the smearing window, the result is rounded down to the nanosecond, such that converting it back to TAI may be off by one nanosecond.
+    pub(super) fn tai_to_smeared_utc<L: LeapSecondTable>(
+        tai: Duration,
+        ts: TimeScale,
+        provider: L,
+    ) -> Duration {
+        let tai_ns = tai.total_nanoseconds();
+        let mut delta_at = 0;
+        for leap_second in SmearedLeapSecond::iers(provider.as_slice()) {
+            let (start, end, tai_start, tai_end) = leap_second.window(ts);
+            if leap_second.is_smeared() && (tai_start..tai_end).contains(&tai_ns) {
+                let smeared =
+                    start + ((tai_ns - tai_start) * (end - start)).div_euclid(tai_end - tai_start);
+                return Duration::from_total_nanoseconds(smeared);
+            }
+            if tai_ns >= leap_second.midnight + leap_second.delta_at_after {
+                delta_at = leap_second.delta_at_after;
+            }
+        }
+        Duration::from_total_nanoseconds(tai_ns - delta_at)
+    }
+
+    /// Converts the provided duration in a smeared UTC time scale into TAI, with the leap seconds of the provider.
+    ///
+    /// Converting the result back into that smeared UTC time scale always returns the initial duration.
+    pub(super) fn smeared_utc_to_tai<L: LeapSecondTable>(
+        smeared: Duration,
+        ts: TimeScale,
+        provider: L,
+    ) -> Duration {
+        let smeared_ns = smeared.total_nanoseconds();
+        let mut delta_at = 0;
+        for leap_second in SmearedLeapSecond::iers(provider.as_slice()) {
+            let (start, end, tai_start, tai_end) = leap_second.window(ts);
+            if leap_second.is_smeared() && (start..end).contains(&smeared_ns) {
+                // Ceiling division, cf. the rounding down of the opposite conversion.
+                let tai = tai_start
+                    - (-(smeared_ns - start) * (tai_end - tai_start)).div_euclid(end - start);
+                return Duration::from_total_nanoseconds(tai);
+            }
+            if smeared_ns >= leap_second.midnight {
+                delta_at = leap_second.delta_at_after;
+            }
+        }
+        Duration::from_total_nanoseconds(smeared_ns + delta_at)
+    }
+}
@@ -0,0 +1,232 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::convert::Infallible;
+use core::f64::consts::TAU;
+
+use crate::{Duration, Epoch, TimeScale, Unit, NANOSECONDS_PER_DAY};
+
+#[cfg(feature = "std")]
+use super::ut1::Ut1Provider;
+#[cfg(feature = "std")]
+use crate::HifitimeError;
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+/// Number of fixed point iterations used to find the epoch of a local solar time.
+const SOLAR_TIME_ITERATIONS: usize = 3;
+
+/// Wraps the provided duration into a time of day, i.e. within [0, 24 hours).
+fn time_of_day(duration: Duration) -> Duration {
+    Duration::from_total_nanoseconds(
+        duration
+            .total_nanoseconds()
+            .rem_euclid(i128::from(NANOSECONDS_PER_DAY)),
+    )
+}
+
+/// Returns the local mean solar time at the provided east longitude in degrees, from the provided UT1 (or approximately UT1) duration past J1900.
+fn local_mean_solar_time(ut1_duration: Duration, east_longitude_deg: f64) -> Duration {
+    time_of_day(ut1_duration + Unit::Day * (east_longitude_deg / 360.0))
+}
+
+/// Wraps the provided duration within [-12 hours, 12 hours).
+fn half_day_wrap(duration: Duration) -> Duration {
+    time_of_day(duration + Unit::Hour * 12) - Unit::Hour * 12
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns the equation of time at this epoch, i.e. the apparent solar time minus the mean solar time, which varies between about -14 and +16 minutes over the year.
+    ///
+    /// This uses the low precision solar coordinates of Meeus, _Astronomical Algorithms_ (1998), chapter 28, and is within a few seconds of the apparent Sun.
+    pub fn equation_of_time(&self) -> Duration {
+        let t = self.to_tt_centuries_j2k();
+        // Geometric mean longitude and mean anomaly of the Sun, and eccentricity of the orbit of the Earth
+        let l0 = (280.466_46 + (36_000.769_83 + 0.000_303_2 * t) * t).to_radians();
+        let m = (357.529_11 + (35_999.050_29 - 0.000_153_7 * t) * t).to_radians();
+        let e = 0.016_708_634 - (0.000_042_037 + 0.000_000_126_7 * t) * t;
+        // Mean obliquity of the ecliptic (IAU 1980)
+        let obliquity = (23.439_291_111 - (46.815_0 + (0.000_59 - 0.001_813 * t) * t) * t / 3600.0)
+            .to_radians();
+        let y = (obliquity / 2.0).tan().powi(2);
+
+        let equation_rad = y * (2.0 * l0).sin() - 2.0 * e * m.sin()
+            + 4.0 * e * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * e * e * (2.0 * m).sin();
+
+        Unit::Day * (equation_rad / TAU)
+    }
+
+    /// Returns the Local Mean Solar Time (LMST) at the provided east longitude on Earth, in degrees, as a time of day within [0, 24 hours).
+    ///
+    /// This is UT1 offset by four minutes per degree of longitude, using the provided UT1 provider.
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch, cf. [Epoch::to_local_mean_solar_time_from_utc] to approximate UT1 with UTC.
+    #[cfg(feature = "std")]
+    pub fn to_local_mean_solar_time(
+        &self,
+        east_longitude_deg: f64,
+        provider: &Ut1Provider,
+    ) -> Result<Duration, HifitimeError> {
+        Ok(local_mean_solar_time(
+            self.try_to_ut1_duration(provider)?,
+            east_longitude_deg,
+        ))
+    }
+
+    /// Returns the Local True Solar Time (LTST), also known as the local apparent solar time, at the provided east longitude on Earth, in degrees,
+    /// as a time of day within [0, 24 hours), using the provided UT1 provider.
+    ///
+    /// This is the Local Mean Solar Time corrected by the [equation of time](Epoch::equation_of_time), i.e. the time shown by a sundial.
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data at this epoch, cf. [Epoch::to_local_true_solar_time_from_utc] to approximate UT1 with UTC.
+    #[cfg(feature = "std")]
+    pub fn to_local_true_solar_time(
+        &self,
+        east_longitude_deg: f64,
+        provider: &Ut1Provider,
+    ) -> Result<Duration, HifitimeError> {
+        Ok(time_of_day(
+            self.to_local_mean_solar_time(east_longitude_deg, provider)? + self.equation_of_time(),
+        ))
+    }
+
+    /// Returns the first epoch at or after this one at which the Local Mean Solar Time at the provided east longitude, in degrees, is the provided time of day,
+    /// using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data around this epoch.
+    #[cfg(feature = "std")]
+    pub fn next_local_mean_solar_time(
+        &self,
+        east_longitude_deg: f64,
+        local_time_of_day: Duration,
+        provider: &Ut1Provider,
+    ) -> Result<Self, HifitimeError> {
+        self.next_solar_time(local_time_of_day, |epoch| {
+            epoch.to_local_mean_solar_time(east_longitude_deg, provider)
+        })
+    }
+
+    /// Returns the first epoch at or after this one at which the Local True Solar Time at the provided east longitude, in degrees, is the provided time of day,
+    /// using the provided UT1 provider.
+    ///
+    /// Returns [HifitimeError::Ut1Unavailable] if the provider has no UT1 data around this epoch.
+    #[cfg(feature = "std")]
+    pub fn next_local_true_solar_time(
+        &self,
+        east_longitude_deg: f64,
+        local_time_of_day: Duration,
+        provider: &Ut1Provider,
+    ) -> Result<Self, HifitimeError> {
+        self.next_solar_time(local_time_of_day, |epoch| {
+            epoch.to_local_true_solar_time(east_longitude_deg, provider)
+        })
+    }
+
+    #[must_use]
+    /// Returns the Local Mean Solar Time (LMST) at the provided east longitude on Earth, in degrees, as a time of day within [0, 24 hours),
+    /// approximating UT1 with UTC.
+    ///
+    /// UTC is kept within 0.9 seconds of UT1, so this is off by as much, cf. [Epoch::to_local_mean_solar_time] to use UT1 data.
+    pub fn to_local_mean_solar_time_from_utc(&self, east_longitude_deg: f64) -> Duration {
+        local_mean_solar_time(
+            self.to_time_scale(TimeScale::UTC).duration,
+            east_longitude_deg,
+        )
+    }
+
+    #[must_use]
+    /// Returns the Local True Solar Time (LTST) at the provided east longitude on Earth, in degrees, as a time of day within [0, 24 hours),
+    /// approximating UT1 with UTC.
+    ///
+    /// UTC is kept within 0.9 seconds of UT1, so this is off by as much, cf. [Epoch::to_local_true_solar_time] to use UT1 data.
+    pub fn to_local_true_solar_time_from_utc(&self, east_longitude_deg: f64) -> Duration {
+        time_of_day(
+            self.to_local_mean_solar_time_from_utc(east_longitude_deg) + self.equation_of_time(),
+        )
+    }
+
+    #[must_use]
+    /// Returns the first epoch at or after this one at which the Local Mean Solar Time at the provided east longitude, in degrees, is the provided time of day,
+    /// approximating UT1 with UTC as [Epoch::to_local_mean_solar_time_from_utc].
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TimeUnits};
+    ///
+    /// // Next descending node of a sun-synchronous orbit at 10:30 LMST over 15 degrees East
+    /// let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 3, 1);
+    /// let node = epoch.next_local_mean_solar_time_from_utc(15.0, 10.hours() + 30.minutes());
+    /// assert_eq!(node, Epoch::from_gregorian_utc_hms(2024, 3, 1, 9, 30, 0));
+    /// ```
+    pub fn next_local_mean_solar_time_from_utc(
+        &self,
+        east_longitude_deg: f64,
+        local_time_of_day: Duration,
+    ) -> Self {
+        let next = self.next_solar_time(local_time_of_day, |epoch| {
+            Ok::<_, Infallible>(epoch.to_local_mean_solar_time_from_utc(east_longitude_deg))
+        });
+        match next {
+            Ok(epoch) => epoch,
+            Err(never) => match never {},
+        }
+    }
+
+    #[must_use]
+    /// Returns the first epoch at or after this one at which the Local True Solar Time at the provided east longitude, in degrees, is the provided time of day,
+    /// approximating UT1 with UTC as [Epoch::to_local_true_solar_time_from_utc].
+    pub fn next_local_true_solar_time_from_utc(
+        &self,
+        east_longitude_deg: f64,
+        local_time_of_day: Duration,
+    ) -> Self {
+        let next = self.next_solar_time(local_time_of_day, |epoch| {
+            Ok::<_, Infallible>(epoch.to_local_true_solar_time_from_utc(east_longitude_deg))
+        });
+        match next {
+            Ok(epoch) => epoch,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Returns the first epoch at or after this one at which the provided solar time is the provided time of day.
+    fn next_solar_time<E, F: Fn(&Self) -> Result<Duration, E>>(
+        &self,
+        local_time_of_day: Duration,
+        solar_time: F,
+    ) -> Result<Self, E> {
+        let target = time_of_day(local_time_of_day);
+        let first_guess = *self + time_of_day(target - solar_time(self)?);
+        let epoch = first_guess.refine_solar_time(target, &solar_time)?;
+        if epoch < *self {
+            // The refinement crossed back before this epoch because the time of day was just reached.
+            (epoch + Unit::Day).refine_solar_time(target, &solar_time)
+        } else {
+            Ok(epoch)
+        }
+    }
+
+    /// Refines this first guess of the epoch at which the provided solar time is the target time of day.
+    fn refine_solar_time<E, F: Fn(&Self) -> Result<Duration, E>>(
+        mut self,
+        target: Duration,
+        solar_time: &F,
+    ) -> Result<Self, E> {
+        // Solar time runs at the rate of UT1 plus the drift of the equation of time (at most 30 seconds per day),
+        // and UTC may jump by a leap second, so the first guess is off by at most a few seconds.
+        for _ in 0..SOLAR_TIME_ITERATIONS {
+            self += half_day_wrap(target - solar_time(&self)?);
+        }
+        Ok(self)
+    }
+}
@@ -0,0 +1,257 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use core::f64::consts::TAU;
+
+use super::rem_euclid_f64;
+use crate::{Duration, Epoch, TimeScale, Unit, ET_EPOCH_S};
+
+#[cfg(not(feature = "std"))]
+#[allow(unused_imports)] // Import is indeed used.
+use num_traits::Float;
+
+/// Number of days in a Julian millennium, which is the time argument of the Fairhead & Bretagnon series.
+const DAYS_PER_JULIAN_MILLENNIUM: f64 = 365_250.0;
+
+/// Periodic terms of order zero of the Fairhead & Bretagnon (1990) series, as amplitude (seconds),
+/// frequency (radians per Julian millennium), and phase (radians), from SOFA `iauDtdb`.
+const FB_T0: [(f64, f64, f64); 40] = [
+    (1656.674564e-6, 6283.075849991, 6.240054195),
+    (22.417471e-6, 5753.384884897, 4.296977442),
+    (13.839792e-6, 12566.151699983, 6.196904410),
+    (4.770086e-6, 529.690965095, 0.444401603),
+    (4.676740e-6, 6069.776754553, 4.021195093),
+    (2.256707e-6, 213.299095438, 5.543113262),
+    (1.694205e-6, -3.523118349, 5.025132748),
+    (1.554905e-6, 77713.771467920, 5.198467090),
+    (1.276839e-6, 7860.419392439, 5.988822341),
+    (1.193379e-6, 5223.693919802, 3.649823730),
+    (1.115322e-6, 3930.209696220, 1.422745069),
+    (0.794185e-6, 11506.769769794, 2.322313077),
+    (0.447061e-6, 26.298319800, 3.615796498),
+    (0.435206e-6, -398.149003408, 4.349338347),
+    (0.600309e-6, 1577.343542448, 2.678271909),
+    (0.496817e-6, 6208.294251424, 5.696701824),
+    (0.486306e-6, 5884.926846583, 0.520007179),
+    (0.432392e-6, 74.781598567, 2.435898309),
+    (0.468597e-6, 6244.942814354, 5.866398759),
+    (0.375510e-6, 5507.553238667, 4.103476804),
+    (0.243085e-6, -775.522611324, 3.651837925),
+    (0.173435e-6, 18849.227549974, 6.153743485),
+    (0.230685e-6, 5856.477659115, 4.773852582),
+    (0.203747e-6, 12036.460734888, 4.333987818),
+    (0.143935e-6, -796.298006816, 5.957517795),
+    (0.159080e-6, 10977.078804699, 1.890075226),
+    (0.119979e-6, 38.133035638, 4.551585768),
+    (0.118971e-6, 5486.777843175, 1.914547226),
+    (0.116120e-6, 1059.381930189, 0.873504123),
+    (0.137927e-6, 11790.629088659, 1.135934669),
+    (0.098358e-6, 2544.314419883, 0.092793886),
+    (0.101868e-6, -5573.142801634, 5.984503847),
+    (0.080164e-6, 206.185548437, 2.095377709),
+    (0.079645e-6, 4694.002954708, 2.949233637),
+    (0.062617e-6, 20.775395492, 2.654394814),
+    (0.075019e-6, 2942.463423292, 4.980931759),
+    (0.064397e-6, 5746.271337896, 1.280308748),
+    (0.063814e-6, 5760.498431898, 4.167901731),
+    (0.048042e-6, 2146.165416475, 1.495846011),
+    (0.048373e-6, 155.420399434, 2.251573730),
+];
+
+/// Periodic terms of order one of the Fairhead & Bretagnon (1990) series, cf. [FB_T0].
+const FB_T1: [(f64, f64, f64); 6] = [
+    (102.156724e-6, 6283.075849991, 4.249032005),
+    (1.706807e-6, 12566.151699983, 4.205904248),
+    (0.269668e-6, 213.299095438, 3.400290479),
+    (0.265919e-6, 529.690965095, 5.836047367),
+    (0.210568e-6, -3.523118349, 6.262738348),
+    (0.077996e-6, 5223.693919802, 4.670344204),
+];
+
+/// Periodic terms of order two of the Fairhead & Bretagnon (1990) series, cf. [FB_T0].
+const FB_T2: [(f64, f64, f64); 3] = [
+    (4.322990e-6, 6283.075849991, 2.642893748),
+    (0.406495e-6, 0.000000000, 4.712388980),
+    (0.122605e-6, 12566.151699983, 2.438140634),
+];
+
+/// Periodic terms of order three of the Fairhead & Bretagnon (1990) series, cf. [FB_T0].
+const FB_T3: [(f64, f64, f64); 1] = [(0.143388e-6, 6283.075849991, 1.131453581)];
+
+/// Periodic terms of order four of the Fairhead & Bretagnon (1990) series, cf. [FB_T0].
+const FB_T4: [(f64, f64, f64); 1] = [(0.003826e-6, 6283.075849991, 5.705257275)];
+
+/// Location of an observer on the Earth, used for the topocentric terms of TDB - TT.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TdbObserver {
+    /// East longitude of the observer, in degrees.
+    pub east_longitude_deg: f64,
+    /// Distance of the observer from the Earth spin axis, in kilometers.
+    pub spin_axis_distance_km: f64,
+    /// Distance of the observer north of the Earth equatorial plane, in kilometers.
+    pub equatorial_plane_distance_km: f64,
+}
+
+impl TdbObserver {
+    /// Builds a new observer from its east longitude (degrees), its distance from the Earth spin axis (km), and its distance north of the equatorial plane (km).
+    pub const fn new(
+        east_longitude_deg: f64,
+        spin_axis_distance_km: f64,
+        equatorial_plane_distance_km: f64,
+    ) -> Self {
+        Self {
+            east_longitude_deg,
+            spin_axis_distance_km,
+            equatorial_plane_distance_km,
+        }
+    }
+}
+
+/// Model used to compute the difference between Dynamic Barycentric Time (TDB) and Terrestrial Time (TT).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum TdbModel {
+    /// Two-term approximation of NAIF SPICE, used by [TimeScale::TDB], accurate to about 10 µs.
+    #[default]
+    Fast,
+    /// Fairhead & Bretagnon (1990) series as implemented in SOFA `iauDtdb`, with the topocentric terms if an observer is provided.
+    ///
+    /// This series is truncated to its largest terms: each omitted term has an amplitude below 50 ns, and the result matches the SOFA
+    /// `iauDtdb` test case to within 10 ns. The topocentric terms are computed with UT1, cf. [TimeScale::UT1].
+    FairheadBretagnon(Option<TdbObserver>),
+}
+
+impl TdbModel {
+    /// Returns TDB - TT at the provided epoch, using this model.
+    pub fn tdb_minus_tt(&self, epoch: Epoch) -> Duration {
+        match self {
+            Self::Fast => epoch.to_tdb_duration() - epoch.to_tt_since_j2k(),
+            Self::FairheadBretagnon(observer) => {
+                let millennia =
+                    epoch.to_tt_since_j2k().to_unit(Unit::Day) / DAYS_PER_JULIAN_MILLENNIUM;
+                let mut seconds = Self::fairhead_bretagnon(millennia);
+                if let Some(observer) = observer {
+                    let ut1_days = epoch
+                        .to_time_scale(TimeScale::UT1)
+                        .duration
+                        .to_unit(Unit::Day);
+                    seconds +=
+                        Self::topocentric(millennia, rem_euclid_f64(ut1_days, 1.0), observer);
+                }
+                Unit::Second * seconds
+            }
+        }
+    }
+
+    /// Geocentric part of TDB - TT in seconds, where the time argument is in Julian millennia since J2000.
+    fn fairhead_bretagnon(t: f64) -> f64 {
+        let series = |terms: &[(f64, f64, f64)]| -> f64 {
+            terms
+                .iter()
+                .map(|(amplitude, frequency, phase)| amplitude * (frequency * t + phase).sin())
+                .sum()
+        };
+
+        let w = series(&FB_T0)
+            + t * (series(&FB_T1)
+                + t * (series(&FB_T2) + t * (series(&FB_T3) + t * series(&FB_T4))));
+
+        // Adjustments to use JPL planetary masses instead of IAU.
+        let wj = 0.00065e-6 * (6069.776754 * t + 4.021194).sin()
+            + 0.00033e-6 * (213.299095 * t + 5.543132).sin()
+            - 0.00196e-6 * (6208.294251 * t + 5.696701).sin()
+            - 0.00173e-6 * (74.781599 * t + 2.435900).sin()
+            + 0.03638e-6 * t * t;
+
+        w + wj
+    }
+
+    /// Topocentric part of TDB - TT in seconds (Moyer 1981 and Murray 1983), where the time argument is in Julian millennia since J2000.
+    fn topocentric(t: f64, ut1_day_fraction: f64, observer: &TdbObserver) -> f64 {
+        let u = observer.spin_axis_distance_km;
+        let v = observer.equatorial_plane_distance_km;
+        let tsol = ut1_day_fraction * TAU + observer.east_longitude_deg.to_radians();
+
+        // Fundamental arguments of the Sun, the Moon and the planets
+        let w = t / 3600.0;
+        let elsun = rem_euclid_f64(280.46645683 + 1296027711.03429 * w, 360.0).to_radians();
+        let emsun = rem_euclid_f64(357.52910918 + 1295965810.481 * w, 360.0).to_radians();
+        let d = rem_euclid_f64(297.85019547 + 16029616012.090 * w, 360.0).to_radians();
+        let elj = rem_euclid_f64(34.35151874 + 109306899.89453 * w, 360.0).to_radians();
+        let els = rem_euclid_f64(50.07744430 + 44046398.47038 * w, 360.0).to_radians();
+
+        0.00029e-10 * u * (tsol + elsun - els).sin()
+            + 0.00100e-10 * u * (tsol - 2.0 * emsun).sin()
+            + 0.00133e-10 * u * (tsol - d).sin()
+            + 0.00133e-10 * u * (tsol + elsun - elj).sin()
+            - 0.00229e-10 * u * (tsol + 2.0 * elsun + emsun).sin()
+            - 0.02200e-10 * v * (elsun + emsun).cos()
+            + 0.05312e-10 * u * (tsol - emsun).sin()
+            - 0.13677e-10 * u * (tsol + 2.0 * elsun).sin()
+            - 1.31840e-10 * v * elsun.cos()
+            + 3.17679e-10 * u * tsol.sin()
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns the Dynamic Barycentric Time (TDB) as a Duration since J2000, computed with the provided TDB model.
+    ///
+    /// [TdbModel::Fast] returns the same value as [Epoch::to_tdb_duration].
+    pub fn to_tdb_duration_with_model(&self, model: TdbModel) -> Duration {
+        match model {
+            TdbModel::Fast => self.to_tdb_duration(),
+            _ => self.to_tt_since_j2k() + model.tdb_minus_tt(*self),
+        }
+    }
+
+    #[must_use]
+    /// Returns the Dynamic Barycentric Time (TDB) as seconds since J2000, computed with the provided TDB model.
+    pub fn to_tdb_seconds_with_model(&self, model: TdbModel) -> f64 {
+        self.to_tdb_duration_with_model(model).to_seconds()
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the Dynamic Barycentric Time (TDB) duration since J2000, computed with the provided TDB model.
+    ///
+    /// Except for [TdbModel::Fast], the returned Epoch is in TT: an Epoch in [TimeScale::TDB] would otherwise be converted with the fast model.
+    pub fn from_tdb_duration_with_model(duration_since_j2000: Duration, model: TdbModel) -> Self {
+        match model {
+            TdbModel::Fast => Self::from_tdb_duration(duration_since_j2000),
+            _ => {
+                // TDB - TT varies by less than a nanosecond over two milliseconds, so two iterations suffice.
+                let tdb_as_tt =
+                    Self::from_tt_duration(duration_since_j2000 + Unit::Second * ET_EPOCH_S);
+                let mut epoch = tdb_as_tt;
+                for _ in 0..2 {
+                    epoch = tdb_as_tt - model.tdb_minus_tt(epoch);
+                }
+                epoch
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_tdb {
+    use super::{TdbModel, TdbObserver, DAYS_PER_JULIAN_MILLENNIUM};
+    use crate::JD_J2000;
+
+    #[test]
+    fn sofa_dtdb() {
+        // Test case of SOFA `t_dtdb`: iauDtdb(2448939.5, 0.123, 0.76543, 5.0123, 5525.242, 3190.0)
+        let t = ((2_448_939.5 - JD_J2000) + 0.123) / DAYS_PER_JULIAN_MILLENNIUM;
+        let observer = TdbObserver::new(5.0123_f64.to_degrees(), 5525.242, 3190.0);
+        let dtdb = TdbModel::fairhead_bretagnon(t) + TdbModel::topocentric(t, 0.76543, &observer);
+        assert!(
+            (dtdb - -0.128_036_800_593_699_9e-2).abs() < 10e-9,
+            "{dtdb:e}"
+        );
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use std::{fs::File, io::Read, path::Path};
+
+use core::ops::Index;
+
+use crate::{Duration, Epoch, HifitimeError, ParsingError, Unit};
+
+/// A TT(BIPM) correction, as published by the BIPM for a given date.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TtBipmCorrection {
+    /// Modified Julian Date in TAI of this correction.
+    pub mjd_tai: f64,
+    /// TT(BIPM) - TAI - 32.184 s, in microseconds.
+    pub correction_us: f64,
+}
+
+impl TtBipmCorrection {
+    pub const fn new(mjd_tai: f64, correction_us: f64) -> Self {
+        Self {
+            mjd_tai,
+            correction_us,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Debug, Default)]
+/// A TT(BIPM) provider that uses a TT(BIPM) file as published yearly by the BIPM on <https://webtai.bipm.org/ftp/pub/tai/ttbipm/> .
+///
+/// TT(BIPM) is a realization of Terrestrial Time which differs from TT(TAI), i.e. TAI + 32.184 s, by a few tens of microseconds.
+pub struct TtBipmFile {
+    realization: Option<String>,
+    data: Vec<TtBipmCorrection>,
+}
+
+impl TtBipmFile {
+    /// Builds a TT(BIPM) provider from the provided TT(BIPM) file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening TT(BIPM) file",
+                })
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading TT(BIPM) file",
+            });
+        }
+
+        Self::from_file_str(&contents)
+    }
+
+    /// Builds a TT(BIPM) provider from the contents of a TT(BIPM) file.
+    ///
+    /// Data lines start with the Modified Julian Date followed by TT(BIPM) - TAI - 32.184 s in microseconds; any further column is ignored.
+    /// All other lines are considered to be part of the header.
+    pub fn from_file_str(contents: &str) -> Result<Self, HifitimeError> {
+        let mut me = Self::default();
+
+        for line in contents.lines() {
+            let data: Vec<&str> = line.split_whitespace().collect();
+
+            let mjd_tai = match data.first() {
+                Some(first) => match lexical_core::parse::<u32>(first.as_bytes()) {
+                    Ok(mjd) => f64::from(mjd),
+                    Err(_) => {
+                        // Header line, which may name the realization.
+                        if me.realization.is_none() {
+                            me.realization = data
+                                .iter()
+                                .find(|word| word.starts_with("TT(BIPM"))
+                                .map(|word| word.trim_end_matches(|c| c != ')').to_string());
+                        }
+                        continue;
+                    }
+                },
+                None => continue,
+            };
+
+            let correction_us: f64 = match data.get(1) {
+                Some(value) => match lexical_core::parse(value.as_bytes()) {
+                    Ok(val) => val,
+                    Err(_) => {
+                        return Err(HifitimeError::Parse {
+                            source: ParsingError::ValueError,
+                            details: "TT(BIPM) correction is not numeric",
+                        })
+                    }
+                },
+                None => {
+                    return Err(HifitimeError::Parse {
+                        source: ParsingError::UnknownFormat,
+                        details: "TT(BIPM) file should have at least two columns",
+                    })
+                }
+            };
+
+            if me.data.last().is_some_and(|prev| prev.mjd_tai >= mjd_tai) {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnknownFormat,
+                    details: "TT(BIPM) file dates should be increasing",
+                });
+            }
+
+            me.data.push(TtBipmCorrection::new(mjd_tai, correction_us));
+        }
+
+        if me.data.is_empty() {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::NothingToParse,
+                details: "TT(BIPM) file has no data",
+            });
+        }
+
+        Ok(me)
+    }
+
+    /// Returns the name of this realization, e.g. `TT(BIPM23)`, if found in the file header.
+    pub fn realization(&self) -> Option<&str> {
+        self.realization.as_deref()
+    }
+
+    /// Returns the number of corrections in this provider.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns whether this provider has no corrections.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns the corrections of this provider, sorted by date.
+    pub fn as_slice(&self) -> &[TtBipmCorrection] {
+        &self.data
+    }
+
+    /// Returns an iterator over the corrections of this provider, sorted by date, which can also be iterated from the most recent one.
+    pub fn iter(&self) -> core::slice::Iter<'_, TtBipmCorrection> {
+        self.data.iter()
+    }
+
+    /// Returns TT(BIPM) - TT(TAI) linearly interpolated at the provided MJD in TAI, or None if outside of the provided corrections.
+    fn correction_at(&self, mjd_tai: f64) -> Option<Duration> {
+        let idx = self.data.partition_point(|c| c.mjd_tai <= mjd_tai);
+        let correction_us = if idx == 0 {
+            return None;
+        } else if idx == self.data.len() {
+            let last = self.data[idx - 1];
+            if last.mjd_tai != mjd_tai {
+                return None;
+            }
+            last.correction_us
+        } else {
+            let (prev, next) = (self.data[idx - 1], self.data[idx]);
+            let frac = (mjd_tai - prev.mjd_tai) / (next.mjd_tai - prev.mjd_tai);
+            prev.correction_us + frac * (next.correction_us - prev.correction_us)
+        };
+        Some(Unit::Microsecond * correction_us)
+    }
+}
+
+impl Index<usize> for TtBipmFile {
+    type Output = TtBipmCorrection;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.data.index(index)
+    }
+}
+
+impl<'a> IntoIterator for &'a TtBipmFile {
+    type Item = &'a TtBipmCorrection;
+    type IntoIter = core::slice::Iter<'a, TtBipmCorrection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for TtBipmFile {
+    type Item = TtBipmCorrection;
+    type IntoIter = std::vec::IntoIter<TtBipmCorrection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Returns TT(BIPM) - TT(TAI) at this epoch, linearly interpolated from the provided TT(BIPM) corrections, or None if this epoch is outside of them.
+    pub fn tt_bipm_correction(&self, provider: &TtBipmFile) -> Option<Duration> {
+        provider.correction_at(self.to_mjd_tai_days())
+    }
+
+    #[must_use]
+    /// Returns this epoch in TT(BIPM), as a duration past J1900 counted in TT(BIPM), or None if this epoch is outside of the provided TT(BIPM) corrections.
+    ///
+    /// This is the reading of TT(BIPM), and not an epoch in TT: use [Epoch::from_tt_bipm] for the reverse conversion.
+    ///
+    /// # Example
+    /// ```
+    /// use hifitime::{Epoch, TtBipmFile, Unit};
+    ///
+    /// let provider = TtBipmFile::from_file_str("59580 27.6661\n59585 27.6663\n").unwrap();
+    /// let epoch = Epoch::from_mjd_tai(59_580.0);
+    /// let tt_bipm = epoch.to_tt_bipm(&provider).unwrap();
+    /// assert_eq!(tt_bipm - epoch.to_tt_duration(), Unit::Microsecond * 27.6661);
+    /// assert_eq!(Epoch::from_tt_bipm(tt_bipm, &provider), Some(epoch));
+    /// ```
+    pub fn to_tt_bipm(&self, provider: &TtBipmFile) -> Option<Duration> {
+        let correction = self.tt_bipm_correction(provider)?;
+        Some(self.to_tt_duration() + correction)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided TT(BIPM) duration since 1900 January 01 at midnight, or None if outside of the provided TT(BIPM) corrections.
+    pub fn from_tt_bipm(duration: Duration, provider: &TtBipmFile) -> Option<Self> {
+        // The correction varies by a few nanoseconds per year, so a single iteration suffices.
+        let tt_bipm_as_tt = Self::from_tt_duration(duration);
+        let correction = tt_bipm_as_tt.tt_bipm_correction(provider)?;
+        Some(tt_bipm_as_tt - correction)
+    }
+}
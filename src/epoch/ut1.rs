@@ -11,18 +11,35 @@
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 
+#[cfg(feature = "ut1")]
 use reqwest::{blocking::get, StatusCode};
 
+#[cfg(feature = "ut1")]
 use tabled::settings::Style;
+#[cfg(feature = "ut1")]
 use tabled::{Table, Tabled};
 
+use std::sync::{PoisonError, RwLock};
 use std::{fs::File, io::Read};
 
+#[cfg(feature = "ut1")]
 use core::fmt;
 use core::ops::Index;
 
 use crate::{Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit};
 
+/// The UT1 provider used when converting epochs from or into the UT1 time scale, cf. [Ut1Provider::install].
+static INSTALLED_PROVIDER: RwLock<Option<Ut1Provider>> = RwLock::new(None);
+
+/// Returns TAI - UT1 at the provided TAI duration using the installed UT1 provider, if any.
+pub(crate) fn installed_delta_tai_minus_ut1(tai_duration: Duration) -> Option<Duration> {
+    INSTALLED_PROVIDER
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .and_then(|provider| provider.delta_tai_minus_ut1(tai_duration))
+}
+
 impl Epoch {
     #[must_use]
     /// Initialize an Epoch from the provided UT1 duration since 1900 January 01 at midnight
@@ -30,6 +47,7 @@ impl Epoch {
     /// # Warning
     /// The time scale of this Epoch will be set to TAI! This is to ensure that no additional computations will change the duration since it's stored in TAI.
     /// However, this also means that calling `to_duration()` on this Epoch will return the TAI duration and not the UT1 duration!
+    /// If the provider has no data at that time, UT1 is assumed to match TAI: use [Epoch::try_from_ut1_duration] to catch this case.
     pub fn from_ut1_duration(duration: Duration, provider: Ut1Provider) -> Self {
         let mut e = Self::from_tai_duration(duration);
         // Compute the TAI to UT1 offset at this time.
@@ -40,32 +58,53 @@ impl Epoch {
         e
     }
 
+    /// Initialize an Epoch in TAI from the provided UT1 duration since 1900 January 01 at midnight,
+    /// or returns an error if the provider has no data at that time.
+    pub fn try_from_ut1_duration(
+        duration: Duration,
+        provider: &Ut1Provider,
+    ) -> Result<Self, HifitimeError> {
+        // TAI = UT1 + offset, where the offset is first evaluated at the UT1 duration to approximate the TAI duration.
+        let tai_approx = duration + provider.try_delta_tai_minus_ut1(duration)?;
+        Ok(Self::from_tai_duration(
+            duration + provider.try_delta_tai_minus_ut1(tai_approx)?,
+        ))
+    }
+
     /// Get the accumulated offset between this epoch and UT1, assuming that the provider includes all data.
     pub fn ut1_offset(&self, provider: Ut1Provider) -> Option<Duration> {
-        for delta_tai_ut1 in provider.rev() {
-            if self > &delta_tai_ut1.epoch {
-                return Some(delta_tai_ut1.delta_tai_minus_ut1);
-            }
-        }
-        None
+        provider.delta_tai_minus_ut1(self.to_tai_duration())
     }
 
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UT1
+    ///
+    /// If the provider has no data at this time, UT1 is assumed to match TAI: use [Epoch::try_to_ut1_duration] to catch this case.
     pub fn to_ut1_duration(&self, provider: Ut1Provider) -> Duration {
         // TAI = UT1 + offset <=> UTC = TAI - offset
         self.to_tai_duration() - self.ut1_offset(provider).unwrap_or(Duration::ZERO)
     }
 
+    /// Returns this time in a Duration past J1900 counted in UT1, or an error if the provider has no data at this time.
+    pub fn try_to_ut1_duration(&self, provider: &Ut1Provider) -> Result<Duration, HifitimeError> {
+        let tai = self.to_tai_duration();
+        Ok(tai - provider.try_delta_tai_minus_ut1(tai)?)
+    }
+
     #[must_use]
     /// Returns this time in a Duration past J1900 counted in UT1
+    ///
+    /// # Warning
+    /// The time scale of the returned Epoch is TAI although it holds the UT1 duration, cf. [Epoch::from_ut1_duration].
+    /// Use [Epoch::try_to_ut1_duration] to get the UT1 duration and [Epoch::try_to_time_scale] for an Epoch in the UT1 time scale.
     pub fn to_ut1(&self, provider: Ut1Provider) -> Self {
         Self::from_tai_duration(self.to_ut1_duration(provider))
     }
 }
 
 #[cfg_attr(kani, derive(kani::Arbitrary))]
-#[derive(Copy, Clone, Debug, Default, Tabled)]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "ut1", derive(Tabled))]
 pub struct DeltaTaiUt1 {
     pub epoch: Epoch,
     pub delta_tai_minus_ut1: Duration,
@@ -81,12 +120,52 @@ pub struct Ut1Provider {
 }
 
 impl Ut1Provider {
-    /// Builds a UT1 provided by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short> (short time scale UT1 data) and parsing it.
+    /// Installs this provider as the one used to convert any Epoch from or into the UT1 time scale, replacing the previously installed provider.
+    /// Until a provider is installed, UT1 is assumed to match UTC.
+    pub fn install(self) {
+        *INSTALLED_PROVIDER
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(self);
+    }
+
+    /// Removes and returns the installed provider, if any. UT1 is then assumed to match UTC.
+    pub fn uninstall() -> Option<Self> {
+        INSTALLED_PROVIDER
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+
+    /// Returns TAI - UT1 at the provided TAI duration past J1900, or None if the provider has no data prior to that time.
+    pub fn delta_tai_minus_ut1(&self, tai_duration: Duration) -> Option<Duration> {
+        // The data is sorted by epoch, cf. [Ut1Provider::from_eop_data].
+        let count = self
+            .data
+            .partition_point(|delta_tai_ut1| delta_tai_ut1.epoch.to_tai_duration() < tai_duration);
+        count
+            .checked_sub(1)
+            .map(|idx| self.data[idx].delta_tai_minus_ut1)
+    }
+
+    /// Returns TAI - UT1 at the provided TAI duration past J1900, or an error if the provider has no data prior to that time.
+    pub fn try_delta_tai_minus_ut1(
+        &self,
+        tai_duration: Duration,
+    ) -> Result<Duration, HifitimeError> {
+        self.delta_tai_minus_ut1(tai_duration)
+            .ok_or(HifitimeError::Ut1Unavailable {
+                epoch: Epoch::from_tai_duration(tai_duration),
+            })
+    }
+
+    #[cfg(feature = "ut1")]
+    /// Builds a UT1 provided by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.short> (short time scale UT1 data) and parsing it (requires the `ut1` feature).
     pub fn download_short_from_jpl() -> Result<Self, HifitimeError> {
         Self::download_from_jpl("latest_eop2.short")
     }
 
-    /// Build a UT1 provider by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.long> (long time scale UT1 data) and parsing it.
+    #[cfg(feature = "ut1")]
+    /// Build a UT1 provider by downloading the data from <https://eop2-external.jpl.nasa.gov/eop2/latest_eop2.long> (long time scale UT1 data) and parsing it (requires the `ut1` feature).
     pub fn download_from_jpl(version: &str) -> Result<Self, HifitimeError> {
         match get(format!(
             "https://eop2-external.jpl.nasa.gov/eop2/{}",
@@ -176,8 +255,16 @@ impl Ut1Provider {
                 }
             }
 
+            let epoch = Epoch::from_mjd_tai(mjd_tai_days);
+            if me.data.last().is_some_and(|prev| prev.epoch >= epoch) {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details: "EOP data is not sorted by date",
+                });
+            }
+
             me.data.push(DeltaTaiUt1 {
-                epoch: Epoch::from_mjd_tai(mjd_tai_days),
+                epoch,
                 delta_tai_minus_ut1: delta_ut1_ms * Unit::Millisecond,
             });
         }
@@ -199,6 +286,7 @@ impl Ut1Provider {
     }
 }
 
+#[cfg(feature = "ut1")]
 impl fmt::Display for Ut1Provider {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut table = Table::new(&self.data);
@@ -235,7 +323,7 @@ impl Index<usize> for Ut1Provider {
     }
 }
 
-#[cfg(kani)]
+#[cfg(all(kani, feature = "ut1"))]
 mod kani_harnesses {
     use super::*;
     #[kani::proof]
@@ -8,6 +8,7 @@
  * Documentation: https://nyxspace.com/
  */
 
+use core::fmt;
 use core::num::ParseIntError;
 use snafu::prelude::*;
 
@@ -19,7 +20,7 @@ use lexical_core::Error as LexicalError;
 #[cfg(feature = "ut1")]
 use reqwest::StatusCode;
 
-use crate::Weekday;
+use crate::{Epoch, Weekday};
 
 /// Errors handles all oddities which may occur in this library.
 #[non_exhaustive]
@@ -32,12 +33,79 @@ pub enum HifitimeError {
         source: ParsingError,
         details: &'static str,
     },
+    /// A parsing error on the provided line of a file, counted from one, along with the content of that line, cf. [LineContent].
+    #[snafu(display("{source} on line {line} `{content}`, {details}"))]
+    ParseLine {
+        source: ParsingError,
+        details: &'static str,
+        line: usize,
+        content: LineContent,
+    },
     #[snafu(display("epoch initialization from system time failed"))]
     SystemTimeError,
     #[snafu(display("epoch computation failed because {source}"))]
     Duration {
         source: DurationError,
     },
+    #[snafu(display("no UT1 data available at {epoch}"))]
+    Ut1Unavailable {
+        epoch: Epoch,
+    },
+}
+
+/// The content of a line which failed to parse, truncated to its first 64 bytes.
+///
+/// This is stored inline such that errors can be built without allocation, including in a const context.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct LineContent {
+    bytes: [u8; LineContent::CAPACITY],
+    len: u8,
+}
+
+impl LineContent {
+    /// Maximum number of bytes of a line which are kept.
+    pub const CAPACITY: usize = 64;
+
+    /// Copies the provided line, truncated to [LineContent::CAPACITY] bytes on a character boundary.
+    pub const fn new(line: &str) -> Self {
+        let line = line.as_bytes();
+        let mut len = if line.len() < Self::CAPACITY {
+            line.len()
+        } else {
+            Self::CAPACITY
+        };
+        // Do not split a multi-byte character, whose continuation bytes start with 0b10.
+        while len < line.len() && len > 0 && line[len] & 0xC0 == 0x80 {
+            len -= 1;
+        }
+        let mut bytes = [0; Self::CAPACITY];
+        let mut i = 0;
+        while i < len {
+            bytes[i] = line[i];
+            i += 1;
+        }
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the (possibly truncated) line.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len as usize]).unwrap_or_default()
+    }
+}
+
+impl fmt::Display for LineContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for LineContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
 }
 
 #[cfg_attr(kani, derive(kani::Arbitrary))]
@@ -79,6 +147,7 @@ pub enum ParsingError {
         expected: Weekday,
     },
     InvalidTimezone,
+    HashMismatch,
     #[cfg(feature = "std")]
     InOut {
         err: IOError,
@@ -91,7 +160,7 @@ pub enum ParsingError {
 
 #[cfg(test)]
 mod tests {
-    use crate::{HifitimeError, ParsingError, TimeScale};
+    use crate::{HifitimeError, LineContent, ParsingError, TimeScale};
 
     #[test]
     fn enum_eq() {
@@ -100,4 +169,29 @@ mod tests {
         assert!(ParsingError::ISO8601 == ParsingError::ISO8601);
         assert!(TimeScale::ET == TimeScale::ET);
     }
+
+    #[test]
+    fn line_content() {
+        assert_eq!(
+            LineContent::new("41317.0 1 1 1972").as_str(),
+            "41317.0 1 1 1972"
+        );
+        // Long lines are truncated without splitting a character.
+        let mut line = [b'#'; LineContent::CAPACITY + 1];
+        line[LineContent::CAPACITY - 1..].copy_from_slice("Δ".as_bytes());
+        let line = core::str::from_utf8(&line).unwrap();
+        assert_eq!(
+            LineContent::new(line).as_str(),
+            &line[..LineContent::CAPACITY - 1]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn line_content_debug() {
+        assert_eq!(
+            format!("{:?}", LineContent::new("1 \"2\"")),
+            "\"1 \\\"2\\\"\""
+        );
+    }
 }
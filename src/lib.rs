@@ -48,13 +48,15 @@ pub const SECONDS_PER_YEAR_I64: i64 = 31_557_600;
 pub const SECONDS_PER_TROPICAL_YEAR: f64 = 31_556_925.974_7;
 /// `SECONDS_PER_SIDEREAL_YEAR` corresponds to the number of seconds per sidereal year from [NIST](https://www.nist.gov/pml/special-publication-811/nist-guide-si-appendix-b-conversion-factors/nist-guide-si-appendix-b9#TIME).
 pub const SECONDS_PER_SIDEREAL_YEAR: f64 = 31_558_150.0;
+/// `SECONDS_PER_SOL` corresponds to the number of seconds per Martian mean solar day (sol) from [Allison & McEwen (2000)](https://doi.org/10.1016/S0032-0633(99)00092-6).
+pub const SECONDS_PER_SOL: f64 = 88_775.244_147;
 
 // Epoch formatting module is called `efmt` to avoid collision with `std::fmt` and `core::fmt`.
 pub mod efmt;
 mod parser;
 
 pub mod errors;
-pub use errors::{DurationError, HifitimeError, ParsingError};
+pub use errors::{DurationError, HifitimeError, LineContent, ParsingError};
 
 mod epoch;
 pub use epoch::*;
@@ -77,6 +79,14 @@ pub use weekday::*;
 mod month;
 pub use month::*;
 
+#[cfg(feature = "std")]
+mod text_kernel;
+
+#[cfg(feature = "std")]
+pub mod sclk;
+#[cfg(feature = "std")]
+pub use sclk::Sclk;
+
 pub mod prelude {
     pub use crate::efmt::{Format, Formatter};
     pub use crate::{
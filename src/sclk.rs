@@ -0,0 +1,412 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Spacecraft clock (SCLK) support from NAIF type 1 SCLK kernels, cf. <https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/sclk.html>.
+
+use std::{fs::File, io::Read, path::Path};
+
+use crate::text_kernel::TextKernel;
+use crate::{Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit};
+
+/// Field delimiters accepted when parsing an SCLK string.
+const SCLK_DELIMITERS: [char; 5] = ['.', ':', '-', ',', ' '];
+
+/// A spacecraft clock, parsed from a NAIF type 1 SCLK kernel.
+///
+/// This mirrors the SPICE routines to convert between SCLK strings, encoded SCLK ticks and epochs:
+/// + [Sclk::encode] and [Sclk::decode] behave like `scencd` and `scdecd`;
+/// + [Sclk::ticks_to_epoch] and [Sclk::epoch_to_ticks] behave like `sct2e` and `sce2c`;
+/// + [Sclk::string_to_epoch] and [Sclk::epoch_to_string] behave like `scs2e` and `sce2s`.
+///
+/// Encoded SCLK ticks are counted continuously from the start of the first partition, in units of the least significant field of the clock.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sclk {
+    /// NAIF ID of the spacecraft, e.g. -82 for Cassini.
+    pub naif_id: i32,
+    /// Time scale of the parallel time of the coefficients, either ET (TDB) or TT.
+    pub time_scale: TimeScale,
+    /// Modulus of each field of the clock, from the most significant field.
+    pub moduli: Vec<u64>,
+    /// Offset of each field of the clock, from the most significant field.
+    pub offsets: Vec<u64>,
+    /// Delimiter between the fields when formatting an SCLK string.
+    pub output_delimiter: char,
+    /// Start and end of each partition, in ticks of the clock count.
+    pub partitions: Vec<(f64, f64)>,
+    /// Coefficient records: encoded SCLK ticks, parallel time in seconds past J2000, and rate in seconds per most significant count.
+    pub coefficients: Vec<[f64; 3]>,
+}
+
+impl Sclk {
+    /// Builds a spacecraft clock from the provided type 1 SCLK kernel file, for the provided (negative) spacecraft NAIF ID.
+    pub fn from_path<P: AsRef<Path>>(path: P, naif_id: i32) -> Result<Self, HifitimeError> {
+        let mut f = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::InOut { err: e.kind() },
+                    details: "opening SCLK kernel",
+                })
+            }
+        };
+
+        let mut contents = String::new();
+        if let Err(e) = f.read_to_string(&mut contents) {
+            return Err(HifitimeError::Parse {
+                source: ParsingError::InOut { err: e.kind() },
+                details: "reading SCLK kernel",
+            });
+        }
+
+        Self::from_kernel_str(&contents, naif_id)
+    }
+
+    /// Builds a spacecraft clock from the contents of a type 1 SCLK kernel, for the provided (negative) spacecraft NAIF ID.
+    pub fn from_kernel_str(contents: &str, naif_id: i32) -> Result<Self, HifitimeError> {
+        let kernel = TextKernel::parse(contents)?;
+        // Variables are suffixed with the opposite of the NAIF ID, e.g. `SCLK01_MODULI_82` for Cassini.
+        let suffix = -i64::from(naif_id);
+        let single = |name: &str, details: &'static str| -> Result<Option<f64>, HifitimeError> {
+            match kernel.get(&format!("{name}_{suffix}")) {
+                None => Ok(None),
+                Some(_) => {
+                    let values = kernel.numbers(&format!("{name}_{suffix}"), details)?;
+                    match values.as_slice() {
+                        [value] => Ok(Some(*value)),
+                        _ => Err(Self::error(ParsingError::ValueError, details)),
+                    }
+                }
+            }
+        };
+        let list = |name: &str, details: &'static str| {
+            kernel.numbers(&format!("{name}_{suffix}"), details)
+        };
+        let integers = |name: &str, details: &'static str| -> Result<Vec<u64>, HifitimeError> {
+            list(name, details)?
+                .into_iter()
+                .map(|value| {
+                    if value >= 0.0 && value.fract() == 0.0 {
+                        Ok(value as u64)
+                    } else {
+                        Err(Self::error(ParsingError::ValueError, details))
+                    }
+                })
+                .collect()
+        };
+
+        let data_type = single("SCLK_DATA_TYPE", "SCLK data type must be a single number")?;
+        if data_type != Some(1.0) {
+            return Err(Self::error(
+                ParsingError::UnknownFormat,
+                "only type 1 SCLK kernels are supported",
+            ));
+        }
+
+        let time_scale = match single("SCLK01_TIME_SYSTEM", "invalid SCLK time system")? {
+            None | Some(1.0) => TimeScale::ET,
+            Some(2.0) => TimeScale::TT,
+            Some(_) => {
+                return Err(Self::error(
+                    ParsingError::UnsupportedTimeSystem,
+                    "SCLK time system must be 1 (TDB) or 2 (TDT)",
+                ))
+            }
+        };
+
+        let moduli = integers("SCLK01_MODULI", "invalid SCLK field moduli")?;
+        let offsets = integers("SCLK01_OFFSETS", "invalid SCLK field offsets")?;
+        let n_fields = single("SCLK01_N_FIELDS", "invalid SCLK number of fields")?;
+        if moduli.is_empty()
+            || moduli.contains(&0)
+            || moduli.len() != offsets.len()
+            || n_fields.is_some_and(|n| n != moduli.len() as f64)
+        {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK fields moduli and offsets do not match the number of fields",
+            ));
+        }
+        // The ticks of a count and the largest value of each field must fit in 64 bits.
+        if Self::checked_ticks_per_count(&moduli).is_none()
+            || moduli
+                .iter()
+                .zip(&offsets)
+                .any(|(modulus, offset)| (modulus - 1).checked_add(*offset).is_none())
+        {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK fields moduli and offsets overflow 64 bits",
+            ));
+        }
+
+        let output_delimiter = match single("SCLK01_OUTPUT_DELIM", "invalid SCLK delimiter")? {
+            Some(code) if (1.0..=5.0).contains(&code) && code.fract() == 0.0 => {
+                SCLK_DELIMITERS[code as usize - 1]
+            }
+            None => '.',
+            Some(_) => {
+                return Err(Self::error(
+                    ParsingError::ValueError,
+                    "SCLK output delimiter code must be between 1 and 5",
+                ))
+            }
+        };
+
+        let starts = list("SCLK_PARTITION_START", "invalid SCLK partition start")?;
+        let ends = list("SCLK_PARTITION_END", "invalid SCLK partition end")?;
+        if starts.is_empty() || starts.len() != ends.len() {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK partition starts and ends do not match",
+            ));
+        }
+
+        let raw_coefficients = list("SCLK01_COEFFICIENTS", "invalid SCLK coefficients")?;
+        if raw_coefficients.is_empty() || raw_coefficients.len() % 3 != 0 {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK coefficients must be triplets",
+            ));
+        }
+        let coefficients = raw_coefficients
+            .chunks_exact(3)
+            .map(|triplet| [triplet[0], triplet[1], triplet[2]])
+            .collect();
+
+        Ok(Self {
+            naif_id,
+            time_scale,
+            moduli,
+            offsets,
+            output_delimiter,
+            partitions: starts.into_iter().zip(ends).collect(),
+            coefficients,
+        })
+    }
+
+    /// Number of ticks in one count of the most significant field.
+    fn ticks_per_count(&self) -> f64 {
+        // Checked when parsing the kernel, but the moduli may have been changed since.
+        match Self::checked_ticks_per_count(&self.moduli) {
+            Some(ticks) => ticks as f64,
+            None => self.moduli[1..]
+                .iter()
+                .map(|modulus| *modulus as f64)
+                .product(),
+        }
+    }
+
+    /// Number of ticks in one count of the most significant field of the provided moduli, or None if it overflows 64 bits.
+    fn checked_ticks_per_count(moduli: &[u64]) -> Option<u64> {
+        moduli
+            .iter()
+            .skip(1)
+            .try_fold(1_u64, |ticks, modulus| ticks.checked_mul(*modulus))
+    }
+
+    /// Total number of encoded ticks, i.e. the sum of the lengths of all partitions.
+    fn max_ticks(&self) -> f64 {
+        self.partitions.iter().map(|(start, end)| end - start).sum()
+    }
+
+    /// Encodes the provided SCLK string, e.g. `1/1465674964.105`, into continuous ticks, like `scencd`.
+    ///
+    /// If the partition is not specified, the first partition containing this clock count is used.
+    pub fn encode(&self, sclk: &str) -> Result<f64, HifitimeError> {
+        let (partition, count) = match sclk.split_once('/') {
+            Some((partition, count)) => {
+                let partition: usize =
+                    lexical_core::parse(partition.trim().as_bytes()).map_err(|_| {
+                        Self::error(ParsingError::ValueError, "SCLK partition is not an integer")
+                    })?;
+                (Some(partition), count)
+            }
+            None => (None, sclk),
+        };
+
+        let fields: Vec<&str> = count
+            .trim()
+            .split(SCLK_DELIMITERS)
+            .filter(|field| !field.is_empty())
+            .collect();
+        if fields.is_empty() || fields.len() > self.moduli.len() {
+            return Err(Self::error(
+                ParsingError::UnknownFormat,
+                "SCLK string has an invalid number of fields",
+            ));
+        }
+
+        // Missing trailing fields are zero.
+        let mut ticks = 0.0;
+        for (i, modulus) in self.moduli.iter().enumerate() {
+            let value: u64 = match fields.get(i) {
+                Some(field) => lexical_core::parse(field.as_bytes()).map_err(|_| {
+                    Self::error(ParsingError::ValueError, "SCLK field is not an integer")
+                })?,
+                None => self.offsets[i],
+            };
+            if value < self.offsets[i] || value - self.offsets[i] >= *modulus {
+                return Err(Self::error(
+                    ParsingError::ValueError,
+                    "SCLK field is out of range",
+                ));
+            }
+            ticks = ticks * (*modulus as f64) + (value - self.offsets[i]) as f64;
+        }
+
+        let partition = match partition {
+            Some(0) => {
+                return Err(Self::error(
+                    ParsingError::ValueError,
+                    "SCLK partitions start at 1",
+                ))
+            }
+            Some(partition) => partition,
+            None => {
+                self.partitions
+                    .iter()
+                    .position(|(start, end)| (*start..=*end).contains(&ticks))
+                    .ok_or(Self::error(
+                        ParsingError::ValueError,
+                        "SCLK count is not in any partition",
+                    ))?
+                    + 1
+            }
+        };
+
+        let (start, end) = *self.partitions.get(partition - 1).ok_or(Self::error(
+            ParsingError::ValueError,
+            "SCLK partition does not exist",
+        ))?;
+        if ticks < start || ticks > end {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK count is outside of its partition",
+            ));
+        }
+
+        let previous: f64 = self.partitions[..partition - 1]
+            .iter()
+            .map(|(start, end)| end - start)
+            .sum();
+        Ok(previous + ticks - start)
+    }
+
+    /// Decodes the provided continuous ticks into an SCLK string with its partition, like `scdecd`.
+    ///
+    /// The ticks are rounded to the nearest integer, and each field is zero-padded to the width of its largest value.
+    pub fn decode(&self, ticks: f64) -> Result<String, HifitimeError> {
+        let mut ticks = ticks.round();
+        if !(0.0..=self.max_ticks()).contains(&ticks) {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK ticks are outside of the clock partitions",
+            ));
+        }
+
+        let mut partition = 0;
+        for (i, (start, end)) in self.partitions.iter().enumerate() {
+            partition = i;
+            if ticks <= end - start {
+                break;
+            }
+            ticks -= end - start;
+        }
+        let mut count = (self.partitions[partition].0 + ticks) as u64;
+
+        let mut fields = vec![0; self.moduli.len()];
+        for (i, modulus) in self.moduli.iter().enumerate().rev() {
+            // The most significant field holds whatever remains.
+            if i == 0 {
+                fields[i] = count + self.offsets[i];
+            } else {
+                fields[i] = count % modulus + self.offsets[i];
+                count /= modulus;
+            }
+        }
+
+        let mut sclk = format!("{}/", partition + 1);
+        for (i, field) in fields.iter().enumerate() {
+            if i > 0 {
+                sclk.push(self.output_delimiter);
+            }
+            let width = (self.moduli[i] - 1 + self.offsets[i]).to_string().len();
+            sclk.push_str(&format!("{field:0width$}"));
+        }
+        Ok(sclk)
+    }
+
+    /// Converts the provided continuous ticks into an Epoch, like `sct2e`.
+    pub fn ticks_to_epoch(&self, ticks: f64) -> Result<Epoch, HifitimeError> {
+        if !(0.0..=self.max_ticks()).contains(&ticks) {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "SCLK ticks are outside of the clock partitions",
+            ));
+        }
+        // Use the last record which starts before these ticks
+        let record = self
+            .coefficients
+            .iter()
+            .rev()
+            .find(|record| record[0] <= ticks)
+            .unwrap_or(&self.coefficients[0]);
+
+        let counts = (ticks - record[0]) / self.ticks_per_count();
+        let parallel = Unit::Second * record[1] + Unit::Second * (counts * record[2]);
+        Ok(self.parallel_to_epoch(parallel))
+    }
+
+    /// Converts the provided Epoch into continuous (fractional) ticks, like `sce2c`.
+    pub fn epoch_to_ticks(&self, epoch: Epoch) -> Result<f64, HifitimeError> {
+        let parallel = match self.time_scale {
+            TimeScale::TT => epoch.to_tt_since_j2k(),
+            _ => epoch.to_et_duration(),
+        };
+        // Use the last record which starts before this epoch
+        let record = self
+            .coefficients
+            .iter()
+            .rev()
+            .find(|record| Unit::Second * record[1] <= parallel)
+            .unwrap_or(&self.coefficients[0]);
+
+        let elapsed = (parallel - Unit::Second * record[1]).to_seconds();
+        let ticks = record[0] + elapsed / record[2] * self.ticks_per_count();
+        if !(0.0..=self.max_ticks()).contains(&ticks) {
+            return Err(Self::error(
+                ParsingError::ValueError,
+                "epoch is outside of the clock partitions",
+            ));
+        }
+        Ok(ticks)
+    }
+
+    /// Converts the provided SCLK string into an Epoch, like `scs2e`.
+    pub fn string_to_epoch(&self, sclk: &str) -> Result<Epoch, HifitimeError> {
+        self.ticks_to_epoch(self.encode(sclk)?)
+    }
+
+    /// Converts the provided Epoch into an SCLK string, rounded to the nearest tick, like `sce2s`.
+    pub fn epoch_to_string(&self, epoch: Epoch) -> Result<String, HifitimeError> {
+        self.decode(self.epoch_to_ticks(epoch)?)
+    }
+
+    fn parallel_to_epoch(&self, parallel: Duration) -> Epoch {
+        match self.time_scale {
+            TimeScale::TT => Epoch::from_tt_duration(parallel + Unit::Second * crate::ET_EPOCH_S),
+            _ => Epoch::from_et_duration(parallel),
+        }
+    }
+
+    fn error(source: ParsingError, details: &'static str) -> HifitimeError {
+        HifitimeError::Parse { source, details }
+    }
+}
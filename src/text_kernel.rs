@@ -0,0 +1,228 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+//! Minimal parser of NAIF text kernels (e.g. SCLK and LSK), cf. <https://naif.jpl.nasa.gov/pub/naif/toolkit_docs/C/req/kernel.html>.
+
+use std::collections::HashMap;
+
+use crate::{HifitimeError, ParsingError};
+
+/// A value assigned to a variable in the data section of a text kernel.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum KernelValue {
+    Number(f64),
+    Text(String),
+    /// A date prefixed with `@` in the kernel, stored without the `@`.
+    Date(String),
+}
+
+/// The variables of a NAIF text kernel, indexed by their name.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TextKernel {
+    variables: HashMap<String, Vec<KernelValue>>,
+}
+
+impl TextKernel {
+    /// Parses the data sections of the provided text kernel, i.e. everything between `\begindata` and `\begintext` markers.
+    pub(crate) fn parse(contents: &str) -> Result<Self, HifitimeError> {
+        let mut data = String::new();
+        let mut in_data = false;
+        for line in contents.lines() {
+            match line.trim() {
+                "\\begindata" => in_data = true,
+                "\\begintext" => in_data = false,
+                _ if in_data => {
+                    data.push_str(line);
+                    data.push('\n');
+                }
+                _ => {}
+            }
+        }
+
+        let mut me = Self::default();
+        let mut chars = data.chars().peekable();
+
+        loop {
+            // Read the variable name
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            let mut name = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() || c == '=' || c == '+' {
+                    break;
+                }
+                name.push(c);
+                chars.next();
+            }
+            if name.is_empty() {
+                if chars.peek().is_none() {
+                    break;
+                }
+                return Err(Self::error("expected a variable name"));
+            }
+
+            // Read the assignment operator
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            let append = match chars.next() {
+                Some('=') => false,
+                Some('+') if chars.next() == Some('=') => true,
+                _ => return Err(Self::error("expected `=` or `+=` after variable name")),
+            };
+
+            // Read the values, which are either a single value or a list of values in parentheses.
+            let mut values = Vec::new();
+            while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            let in_list = chars.peek() == Some(&'(');
+            if in_list {
+                chars.next();
+            }
+            loop {
+                while chars.peek().is_some_and(|c| c.is_whitespace() || *c == ',') {
+                    chars.next();
+                }
+                match chars.peek() {
+                    None => {
+                        if in_list {
+                            return Err(Self::error("unterminated list of values"));
+                        }
+                        break;
+                    }
+                    Some(')') if in_list => {
+                        chars.next();
+                        break;
+                    }
+                    Some('\'') => {
+                        chars.next();
+                        let mut text = String::new();
+                        loop {
+                            match chars.next() {
+                                None => return Err(Self::error("unterminated string value")),
+                                Some('\'') => {
+                                    // Two consecutive quotes encode a quote.
+                                    if chars.peek() == Some(&'\'') {
+                                        chars.next();
+                                        text.push('\'');
+                                    } else {
+                                        break;
+                                    }
+                                }
+                                Some(c) => text.push(c),
+                            }
+                        }
+                        values.push(KernelValue::Text(text));
+                    }
+                    Some(_) => {
+                        let mut token = String::new();
+                        while let Some(&c) = chars.peek() {
+                            if c.is_whitespace() || c == ',' || c == ')' {
+                                break;
+                            }
+                            token.push(c);
+                            chars.next();
+                        }
+                        if let Some(date) = token.strip_prefix('@') {
+                            values.push(KernelValue::Date(date.to_string()));
+                        } else {
+                            // Fortran style exponents are allowed in text kernels.
+                            let number = token.replace(['D', 'd'], "E");
+                            match lexical_core::parse(number.as_bytes()) {
+                                Ok(value) => values.push(KernelValue::Number(value)),
+                                Err(_) => return Err(Self::error("kernel value is not numeric")),
+                            }
+                        }
+                    }
+                }
+                if !in_list {
+                    break;
+                }
+            }
+
+            let entry = me.variables.entry(name).or_default();
+            if !append {
+                entry.clear();
+            }
+            entry.extend(values);
+        }
+
+        Ok(me)
+    }
+
+    /// Returns the values of the provided variable, if it is defined.
+    pub(crate) fn get(&self, name: &str) -> Option<&[KernelValue]> {
+        self.variables.get(name).map(|values| values.as_slice())
+    }
+
+    /// Returns the numerical values of the provided variable.
+    pub(crate) fn numbers(
+        &self,
+        name: &str,
+        details: &'static str,
+    ) -> Result<Vec<f64>, HifitimeError> {
+        let values = self.get(name).ok_or(HifitimeError::Parse {
+            source: ParsingError::NothingToParse,
+            details,
+        })?;
+        values
+            .iter()
+            .map(|value| match value {
+                KernelValue::Number(number) => Ok(*number),
+                _ => Err(HifitimeError::Parse {
+                    source: ParsingError::ValueError,
+                    details,
+                }),
+            })
+            .collect()
+    }
+
+    fn error(details: &'static str) -> HifitimeError {
+        HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details,
+        }
+    }
+}
+
+#[cfg(test)]
+mod ut_text_kernel {
+    use super::{KernelValue, TextKernel};
+
+    #[test]
+    fn parse_data_sections() {
+        let kernel = TextKernel::parse(
+            "KPL/SCLK\n\\begintext\nIGNORED = ( 1 )\n\\begindata\n\
+             SCLK_KERNEL_ID = ( @2024-01-01/00:00:00.00 )\n\
+             VALUES = ( 1.5D+01, -2 \n 3.0E-1 )\n\
+             NAME = 'It''s'\n\
+             VALUES += 4\n\\begintext\nDone.\n",
+        )
+        .unwrap();
+
+        assert!(kernel.get("IGNORED").is_none());
+        assert_eq!(
+            kernel.get("SCLK_KERNEL_ID").unwrap(),
+            &[KernelValue::Date("2024-01-01/00:00:00.00".to_string())]
+        );
+        assert_eq!(
+            kernel.numbers("VALUES", "").unwrap(),
+            vec![15.0, -2.0, 0.3, 4.0]
+        );
+        assert_eq!(
+            kernel.get("NAME").unwrap(),
+            &[KernelValue::Text("It's".to_string())]
+        );
+        assert!(kernel.numbers("NAME", "").is_err());
+        assert!(TextKernel::parse("\\begindata\nA = ( 1 2\n").is_err());
+    }
+}
@@ -0,0 +1,122 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::{Duration, Epoch, Unit};
+
+/// Maximum number of iterations used to invert the offset of a custom time scale.
+const MAX_INVERSION_ITERATIONS: usize = 8;
+
+/// A user-defined time scale, realized as an offset from TAI which may drift over time.
+///
+/// Durations in a custom time scale are counted from its [reference epoch](CustomTimeScale::reference_epoch),
+/// and are related to the TAI duration elapsed since that same reference epoch by:
+///
+/// `custom = elapsed TAI + offset(elapsed TAI)`
+///
+/// By default, the offset is linear: `offset(t) = tai_offset + rate * t`, which models for example a ground
+/// station clock with a fixed bias and drift with respect to TAI. Non-linear time scales should override
+/// [offset_from_tai](CustomTimeScale::offset_from_tai).
+///
+/// # Example
+/// ```
+/// use hifitime::{CustomTimeScale, Duration, Epoch, TimeUnits};
+///
+/// struct StationClock;
+///
+/// impl CustomTimeScale for StationClock {
+///     fn name(&self) -> &'static str {
+///         "STN"
+///     }
+///
+///     fn reference_epoch(&self) -> Epoch {
+///         Epoch::from_gregorian_tai_at_midnight(2024, 1, 1)
+///     }
+///
+///     fn tai_offset(&self) -> Duration {
+///         1.5.milliseconds()
+///     }
+///
+///     fn rate(&self) -> f64 {
+///         1e-9
+///     }
+/// }
+///
+/// let epoch = Epoch::from_gregorian_tai_at_midnight(2024, 1, 2);
+/// // One day after the reference epoch, the clock has drifted by 86.4 µs.
+/// assert_eq!(
+///     epoch.to_custom_duration(&StationClock),
+///     1.days() + 1.5.milliseconds() + 86.4.microseconds()
+/// );
+/// assert_eq!(
+///     Epoch::from_custom_duration(epoch.to_custom_duration(&StationClock), &StationClock),
+///     epoch
+/// );
+/// ```
+pub trait CustomTimeScale {
+    /// Name of this time scale, used when formatting an Epoch in this time scale, cf. [Formatter::to_custom_time_scale](crate::efmt::Formatter::to_custom_time_scale).
+    fn name(&self) -> &'static str;
+
+    /// Epoch from which the durations of this time scale are counted, and at which its offset from TAI is `tai_offset`.
+    fn reference_epoch(&self) -> Epoch;
+
+    /// Offset of this time scale with respect to TAI at its reference epoch, i.e. the reading of this time scale at that epoch.
+    fn tai_offset(&self) -> Duration;
+
+    /// Drift rate of this time scale with respect to TAI, in seconds per second. Defaults to zero.
+    fn rate(&self) -> f64 {
+        0.0
+    }
+
+    /// Returns the offset of this time scale with respect to TAI once `tai_elapsed` has elapsed since its reference epoch.
+    fn offset_from_tai(&self, tai_elapsed: Duration) -> Duration {
+        self.tai_offset() + Unit::Second * (self.rate() * tai_elapsed.to_seconds())
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Initialize an Epoch from the provided duration since the reference epoch of the provided custom time scale.
+    ///
+    /// The returned Epoch is in TAI, since Epochs cannot store a custom time scale.
+    pub fn from_custom_duration<T: CustomTimeScale + ?Sized>(
+        duration: Duration,
+        time_scale: &T,
+    ) -> Self {
+        // Fixed point iteration: the offset varies slowly compared to the elapsed time.
+        let mut tai_elapsed = duration - time_scale.offset_from_tai(duration);
+        for _ in 0..MAX_INVERSION_ITERATIONS {
+            let next = duration - time_scale.offset_from_tai(tai_elapsed);
+            if next == tai_elapsed {
+                break;
+            }
+            tai_elapsed = next;
+        }
+        Self::from_tai_duration(time_scale.reference_epoch().to_tai_duration() + tai_elapsed)
+    }
+
+    #[must_use]
+    /// Initialize an Epoch from the provided number of seconds since the reference epoch of the provided custom time scale.
+    pub fn from_custom_seconds<T: CustomTimeScale + ?Sized>(seconds: f64, time_scale: &T) -> Self {
+        Self::from_custom_duration(Unit::Second * seconds, time_scale)
+    }
+
+    #[must_use]
+    /// Returns the duration since the reference epoch of the provided custom time scale, expressed in that time scale.
+    pub fn to_custom_duration<T: CustomTimeScale + ?Sized>(&self, time_scale: &T) -> Duration {
+        let tai_elapsed = self.to_tai_duration() - time_scale.reference_epoch().to_tai_duration();
+        tai_elapsed + time_scale.offset_from_tai(tai_elapsed)
+    }
+
+    #[must_use]
+    /// Returns the number of seconds since the reference epoch of the provided custom time scale, expressed in that time scale.
+    pub fn to_custom_seconds<T: CustomTimeScale + ?Sized>(&self, time_scale: &T) -> f64 {
+        self.to_custom_duration(time_scale).to_seconds()
+    }
+}
@@ -28,6 +28,14 @@ impl fmt::Display for TimeScale {
             Self::GST => write!(f, "GST"),
             Self::BDT => write!(f, "BDT"),
             Self::QZSST => write!(f, "QZSST"),
+            Self::TCG => write!(f, "TCG"),
+            Self::TCB => write!(f, "TCB"),
+            Self::UT1 => write!(f, "UT1"),
+            Self::GLONASST => write!(f, "GLONASST"),
+            Self::IRNWT => write!(f, "IRNWT"),
+            Self::LTC => write!(f, "LTC"),
+            Self::UTCSLS => write!(f, "UTC-SLS"),
+            Self::UTCSMEAR => write!(f, "UTC-SMEAR"),
         }
     }
 }
@@ -41,6 +49,8 @@ impl fmt::LowerHex for TimeScale {
             Self::GST => write!(f, "GAL"),
             Self::BDT => write!(f, "BDS"),
             Self::QZSST => write!(f, "QZSS"),
+            Self::GLONASST => write!(f, "GLO"),
+            Self::IRNWT => write!(f, "IRN"),
             _ => write!(f, "{self}"),
         }
     }
@@ -61,6 +71,18 @@ impl FromStr for TimeScale {
             Ok(Self::TDB)
         } else if val == "ET" {
             Ok(Self::ET)
+        } else if val == "TCG" {
+            Ok(Self::TCG)
+        } else if val == "TCB" {
+            Ok(Self::TCB)
+        } else if val == "UT1" {
+            Ok(Self::UT1)
+        } else if val == "LTC" {
+            Ok(Self::LTC)
+        } else if val == "UTC-SLS" || val == "UTCSLS" {
+            Ok(Self::UTCSLS)
+        } else if val == "UTC-SMEAR" || val == "UTCSMEAR" {
+            Ok(Self::UTCSMEAR)
         } else if val == "GPST" || val == "GPS" {
             Ok(Self::GPST)
         } else if val == "GST" || val == "GAL" {
@@ -69,6 +91,10 @@ impl FromStr for TimeScale {
             Ok(Self::BDT)
         } else if val == "QZSST" || val == "QZSS" {
             Ok(Self::QZSST)
+        } else if val == "GLONASST" || val == "GLO" {
+            Ok(Self::GLONASST)
+        } else if val == "IRNWT" || val == "IRN" {
+            Ok(Self::IRNWT)
         } else {
             Err(ParsingError::TimeSystem)
         }
@@ -0,0 +1,239 @@
+/*
+ * Hifitime, part of the Nyx Space tools
+ * Copyright (C) 2023 Christopher Rabotin <christopher.rabotin@gmail.com> et al. (cf. https://github.com/nyx-space/hifitime/graphs/contributors)
+ * This Source Code Form is subject to the terms of the Apache
+ * v. 2.0. If a copy of the Apache License was not distributed with this
+ * file, You can obtain one at https://www.apache.org/licenses/LICENSE-2.0.
+ *
+ * Documentation: https://nyxspace.com/
+ */
+
+use crate::{
+    Duration, Epoch, HifitimeError, ParsingError, TimeScale, Unit, NANOSECONDS_PER_SECOND,
+};
+
+/// Number of fixed point iterations used to apply a correction from its right hand side time scale.
+const INVERSION_ITERATIONS: usize = 3;
+
+/// A time offset between two time scales as broadcast by a GNSS constellation, e.g. the GPS UTC parameters
+/// (A0, A1, tot, WNt), the Galileo GPS to Galileo time offset (GGTO), or the BeiDou A0UTC and A1UTC.
+///
+/// The polynomial gives the part of `lhs - rhs` which is not already accounted for by [Epoch::to_time_scale],
+/// i.e. excluding the leap seconds and the fixed offsets between the reference epochs of these time scales:
+///
+/// `correction = a0 + a1 * dt + a2 * dt^2`, where `dt` is the number of seconds from the reference epoch
+///
+/// such that a reading of `t_lhs` corresponds to a reading of `t_rhs = t_lhs - nominal offset - correction`.
+///
+/// When the right hand side is [TimeScale::UTC], the converted epoch is the reading of the UTC realization
+/// broadcast by that constellation, e.g. UTC(USNO) for GPS, cf. [utc_id](GnssTimeCorrection::utc_id).
+///
+/// # Example
+/// ```
+/// use hifitime::{Epoch, GnssTimeCorrection, TimeScale, TimeUnits};
+///
+/// let correction = GnssTimeCorrection::from_rinex_str(
+///     "GPUT  0.1000000000E-07 0.000000000E+00 233472 2240 G10    2 TIME SYSTEM CORR",
+/// )
+/// .unwrap();
+/// assert_eq!(correction.lhs, TimeScale::GPST);
+/// assert_eq!(correction.rhs, TimeScale::UTC);
+///
+/// let gpst = Epoch::from_gregorian(2022, 12, 13, 12, 0, 0, 0, TimeScale::GPST);
+/// let utc = gpst.to_time_scale_with_correction(TimeScale::UTC, &correction).unwrap();
+/// assert_eq!(gpst.to_time_scale(TimeScale::UTC) - utc, 10.nanoseconds());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GnssTimeCorrection {
+    /// Time scale whose offset is broadcast.
+    pub lhs: TimeScale,
+    /// Time scale with respect to which the offset is broadcast.
+    pub rhs: TimeScale,
+    /// Reference epoch of the polynomial, e.g. tot and WNt for the GPS UTC parameters.
+    pub reference_epoch: Epoch,
+    /// Constant term of the polynomial, in seconds.
+    pub a0: f64,
+    /// Linear term of the polynomial, in seconds per second.
+    pub a1: f64,
+    /// Quadratic term of the polynomial, in seconds per second squared.
+    pub a2: f64,
+    /// Identifier of the UTC realization as encoded in RINEX, only relevant when the right hand side is UTC:
+    /// 1 for UTC(NIST), 2 for UTC(USNO), 3 for UTC(SU), 4 for UTC(BIPM), 5 for UTC(Europe Lab), 6 for UTC(CRL), 7 for UTC(NTSC), and 0 if unknown.
+    pub utc_id: u8,
+}
+
+impl GnssTimeCorrection {
+    /// Builds a new correction whose polynomial is `lhs - rhs` in excess of their nominal offset.
+    pub const fn new(
+        lhs: TimeScale,
+        rhs: TimeScale,
+        reference_epoch: Epoch,
+        a0: f64,
+        a1: f64,
+        a2: f64,
+    ) -> Self {
+        Self {
+            lhs,
+            rhs,
+            reference_epoch,
+            a0,
+            a1,
+            a2,
+            utc_id: 0,
+        }
+    }
+
+    /// Returns the correction `lhs - rhs` in excess of their nominal offset at the provided epoch.
+    pub fn correction(&self, epoch: Epoch) -> Duration {
+        let dt = (epoch - self.reference_epoch).to_seconds();
+        Unit::Second * (self.a0 + self.a1 * dt + self.a2 * dt * dt)
+    }
+
+    /// Builds a correction from a `TIME SYSTEM CORR` line of the header of a RINEX 3 or 4 navigation file, e.g.
+    /// `GAUT  0.1862645149D-08-0.888178420D-15 432000 2240        5 TIME SYSTEM CORR`.
+    ///
+    /// The supported correction types are GPUT, GAUT, GLUT, BDUT, QZUT and IRUT to UTC, and GPGA, GLGP, QZGP and IRGP
+    /// between constellations. Note that GPGA is the GPS to Galileo time offset (GGTO) as broadcast by Galileo, i.e. GST - GPST,
+    /// so its left hand side is GST.
+    ///
+    /// The reference week number is in BDT for BDUT, and continuous GPS weeks for the other corrections.
+    pub fn from_rinex_str(line: &str) -> Result<Self, HifitimeError> {
+        let (lhs, rhs) = match field(line, 0, 4)? {
+            "GPUT" => (TimeScale::GPST, TimeScale::UTC),
+            "GAUT" => (TimeScale::GST, TimeScale::UTC),
+            "GLUT" => (TimeScale::GLONASST, TimeScale::UTC),
+            "BDUT" => (TimeScale::BDT, TimeScale::UTC),
+            "QZUT" => (TimeScale::QZSST, TimeScale::UTC),
+            "IRUT" => (TimeScale::IRNWT, TimeScale::UTC),
+            "GPGA" => (TimeScale::GST, TimeScale::GPST),
+            "GLGP" => (TimeScale::GLONASST, TimeScale::GPST),
+            "QZGP" => (TimeScale::QZSST, TimeScale::GPST),
+            "IRGP" => (TimeScale::IRNWT, TimeScale::GPST),
+            _ => {
+                return Err(HifitimeError::Parse {
+                    source: ParsingError::UnsupportedTimeSystem,
+                    details: "unsupported RINEX time system correction",
+                })
+            }
+        };
+
+        let a0 = parse_rinex_float(field(line, 5, 22)?)?;
+        let a1 = parse_rinex_float(field(line, 22, 38)?)?;
+        let reference_s: u32 = parse_rinex_int(field(line, 38, 45)?)?;
+        let week: u32 = parse_rinex_int(field(line, 45, 50)?)?;
+        let utc_id = match field(line, 56, 59)? {
+            "" => 0,
+            utc_id => parse_rinex_int(utc_id)?,
+        };
+
+        let week_time_scale = if lhs == TimeScale::BDT {
+            TimeScale::BDT
+        } else {
+            TimeScale::GPST
+        };
+        let reference_epoch = Epoch::from_time_of_week(
+            week,
+            u64::from(reference_s) * NANOSECONDS_PER_SECOND,
+            week_time_scale,
+        );
+
+        let mut me = Self::new(lhs, rhs, reference_epoch, a0, a1, 0.0);
+        me.utc_id = utc_id;
+        Ok(me)
+    }
+
+    /// Builds all of the supported corrections from the `TIME SYSTEM CORR` lines of the provided RINEX navigation file header.
+    ///
+    /// Parsing stops at the end of the header, and unsupported correction types (e.g. SBUT) are skipped.
+    #[cfg(feature = "std")]
+    pub fn from_rinex_header(contents: &str) -> Result<Vec<Self>, HifitimeError> {
+        let mut corrections = Vec::new();
+        for line in contents.lines() {
+            let label = line.get(60..).unwrap_or_default().trim();
+            if label == "END OF HEADER" {
+                break;
+            } else if label == "TIME SYSTEM CORR" {
+                match Self::from_rinex_str(line) {
+                    Ok(correction) => corrections.push(correction),
+                    Err(HifitimeError::Parse {
+                        source: ParsingError::UnsupportedTimeSystem,
+                        ..
+                    }) => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(corrections)
+    }
+}
+
+impl Epoch {
+    #[must_use]
+    /// Converts this epoch into the provided time scale by applying the provided broadcast correction on top of [Epoch::to_time_scale],
+    /// or returns None if this correction is not between the time scale of this epoch and the provided time scale.
+    ///
+    /// Corrections apply in both directions, e.g. a GPUT correction converts GPST to UTC and UTC to GPST.
+    pub fn to_time_scale_with_correction(
+        &self,
+        ts: TimeScale,
+        correction: &GnssTimeCorrection,
+    ) -> Option<Self> {
+        if self.time_scale == correction.lhs && ts == correction.rhs {
+            let nominal = self.to_time_scale(ts);
+            Some(nominal - correction.correction(*self))
+        } else if self.time_scale == correction.rhs && ts == correction.lhs {
+            // The correction is a function of the left hand side epoch, which is not known yet.
+            let nominal = self.to_time_scale(ts);
+            let mut lhs = nominal;
+            for _ in 0..INVERSION_ITERATIONS {
+                lhs = nominal + correction.correction(lhs);
+            }
+            Some(lhs)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the trimmed field of the provided line between these columns, or an empty string past the end of the line.
+fn field(line: &str, start: usize, end: usize) -> Result<&str, HifitimeError> {
+    if start >= line.len() {
+        return Ok("");
+    }
+    match line.get(start..end.min(line.len())) {
+        Some(field) => Ok(field.trim()),
+        None => Err(HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details: "RINEX time system correction is not ASCII",
+        }),
+    }
+}
+
+/// Parses a RINEX floating point number, whose exponent may be marked with a `D` as in Fortran.
+fn parse_rinex_float(value: &str) -> Result<f64, HifitimeError> {
+    let mut buf = [0_u8; 32];
+    let bytes = value.as_bytes();
+    if bytes.is_empty() || bytes.len() > buf.len() {
+        return Err(HifitimeError::Parse {
+            source: ParsingError::ValueError,
+            details: "RINEX time system correction coefficient is invalid",
+        });
+    }
+    for (dst, src) in buf.iter_mut().zip(bytes) {
+        *dst = match src {
+            b'D' | b'd' => b'E',
+            _ => *src,
+        };
+    }
+    lexical_core::parse(&buf[..bytes.len()]).map_err(|_| HifitimeError::Parse {
+        source: ParsingError::ValueError,
+        details: "RINEX time system correction coefficient is not numeric",
+    })
+}
+
+fn parse_rinex_int<T: lexical_core::FromLexical>(value: &str) -> Result<T, HifitimeError> {
+    lexical_core::parse(value.as_bytes()).map_err(|_| HifitimeError::Parse {
+        source: ParsingError::ValueError,
+        details: "RINEX time system correction reference time is not an integer",
+    })
+}
@@ -17,7 +17,12 @@ mod kani;
 #[cfg(feature = "serde")]
 use serde_derive::{Deserialize, Serialize};
 
+mod custom;
 mod fmt;
+mod gnss_correction;
+
+pub use custom::CustomTimeScale;
+pub use gnss_correction::GnssTimeCorrection;
 
 use crate::{Duration, Epoch, Unit, SECONDS_PER_DAY};
 
@@ -60,6 +65,11 @@ pub const GST_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
 pub const SECONDS_GST_TAI_OFFSET: f64 = 3_144_268_819.0;
 pub const SECONDS_GST_TAI_OFFSET_I64: i64 = 3_144_268_819;
 
+/// IRNWT (IRNSS/NavIC) reference epoch is 1999 August 22 00:00 UTC, aligned with the GST reference epoch.
+pub const IRNWT_REF_EPOCH: Epoch = GST_REF_EPOCH;
+pub const SECONDS_IRNWT_TAI_OFFSET: f64 = SECONDS_GST_TAI_OFFSET;
+pub const SECONDS_IRNWT_TAI_OFFSET_I64: i64 = SECONDS_GST_TAI_OFFSET_I64;
+
 /// BDT(BeiDou): 2005 Dec 31st Midnight
 /// BDT (BeiDou) reference epoch is 2005 December 31st UTC at midnight. **This time scale is synchronized with UTC.**
 /// |UTC - TAI| = XX Leap Seconds on that day.
@@ -70,12 +80,49 @@ pub const BDT_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
 pub const SECONDS_BDT_TAI_OFFSET: f64 = 3_345_062_433.0;
 pub const SECONDS_BDT_TAI_OFFSET_I64: i64 = 3_345_062_433;
 
-/// The UNIX reference epoch of 1970-01-01 in TAI duration, accounting only for IERS leap seconds.
-pub const UNIX_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+/// GLONASST (GLONASS) reference epoch is 1996 January 01 at midnight in GLONASST, i.e. 1995 December 31 at 21:00 UTC.
+/// **This time scale is synchronized with UTC, offset by three hours (Moscow time).**
+/// |UTC - TAI| = 29 Leap Seconds on that day.
+pub const GLONASST_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
+    centuries: 0,
+    nanoseconds: 3_029_432_429_000_000_000,
+});
+/// GLONASST is ahead of UTC by three hours: GLONASST = UTC(SU) + 3h.
+pub const GLONASST_UTC_OFFSET: Duration = Duration {
+    centuries: 0,
+    nanoseconds: 10_800_000_000_000,
+};
+
+/// The UNIX reference epoch of 1970-01-01 midnight UTC, when TAI - UTC was about 8.000082 seconds.
+pub const UNIX_REF_EPOCH: Epoch = Epoch::from_duration(
+    Duration {
+        centuries: 0,
+        nanoseconds: 2_208_988_800_000_000_000,
+    },
+    TimeScale::UTC,
+);
+
+/// The 1977-01-01T00:00:32.184 TT epoch (i.e. 1977-01-01 midnight TAI) at which TT, TCG and TCB are defined to coincide,
+/// cf. IAU 1991 Resolution A4 and IAU 2006 Resolution B3.
+pub const IAU_1977_REF_EPOCH: Epoch = Epoch::from_tai_duration(Duration {
     centuries: 0,
-    nanoseconds: 2_208_988_800_000_000_000,
+    nanoseconds: 2_429_913_600_000_000_000,
 });
 
+/// Defining constant L_G of the rate of TT with respect to TCG, cf. IAU 2000 Resolution B1.9.
+pub const IAU_L_G: f64 = 6.969_290_134e-10;
+/// Defining constant L_B of the rate of TDB with respect to TCB, cf. IAU 2006 Resolution B3.
+pub const IAU_L_B: f64 = 1.550_519_768e-8;
+/// Defining constant TDB0, in seconds, of the offset between TDB and TCB, cf. IAU 2006 Resolution B3.
+pub const IAU_TDB0_S: f64 = -6.55e-5;
+
+/// Secular fractional rate of Coordinated Lunar Time (LTC) with respect to TT: a clock on the lunar surface gains 56.02 µs per day
+/// on a clock on the geoid, cf. Ashby & Patla (2024), "A Relativistic Framework to Establish Coordinate Time on the Moon and Beyond".
+pub const LTC_TT_RATE: f64 = 56.02e-6 / SECONDS_PER_DAY;
+/// Amplitude, in seconds, of the main periodic term of LTC with respect to TT, due to the eccentricity of the lunar orbit: 2√(GM a) e / c²,
+/// with the Earth's GM, and the Moon's semi-major axis a of 384,399 km and eccentricity e of 0.0549.
+pub const LTC_PERIODIC_AMPLITUDE_S: f64 = 4.782e-7;
+
 /// Reference year of the Hifitime prime epoch.
 pub(crate) const HIFITIME_REF_YEAR: i32 = 1900;
 
@@ -103,6 +150,23 @@ pub enum TimeScale {
     BDT,
     /// QZSS Time scale has the same properties as GPST but with dedicated clocks
     QZSST,
+    /// Geocentric Coordinate Time (TCG), whose rate differs from TT by the IAU defining constant L_G
+    TCG,
+    /// Barycentric Coordinate Time (TCB), whose rate differs from TDB by the IAU defining constant L_B, with the J2000 reference epoch like TDB
+    TCB,
+    /// Universal Time (UT1), the time scale following the rotation of the Earth, computed from the installed Earth Orientation Parameters provider (requires the `ut1` feature) or assumed to match UTC otherwise
+    UT1,
+    /// GLONASS Time scale, synchronized with UTC(SU) plus three hours and hence follows leap seconds
+    GLONASST,
+    /// IRNSS/NavIC System Time scale, whose reference epoch is aligned with GST
+    IRNWT,
+    /// Coordinated Lunar Time (LTC), the time kept by clocks on the lunar surface, which gains about 56 µs per day on TT.
+    /// It is modeled from TT with a secular rate and the main periodic term of the lunar orbit, and coincides with TT on 1977-01-01T00:00:32.184 TT.
+    LTC,
+    /// UTC with Smoothed Leap Seconds (UTC-SLS), which spreads each leap second linearly over the last 1000 seconds of the UTC day preceding it
+    UTCSLS,
+    /// Smeared UTC, which spreads each leap second linearly over the 24 hours from noon to noon UTC around it, as done by some NTP servers (e.g. Google Public NTP)
+    UTCSMEAR,
 }
 
 impl Default for TimeScale {
@@ -115,16 +179,30 @@ impl Default for TimeScale {
 impl TimeScale {
     pub(crate) const fn formatted_len(&self) -> usize {
         match &self {
-            Self::QZSST => 5,
+            Self::UTCSMEAR => 9,
+            Self::GLONASST => 8,
+            Self::UTCSLS => 7,
+            Self::QZSST | Self::IRNWT => 5,
             Self::GPST => 4,
-            Self::TAI | Self::TDB | Self::UTC | Self::GST | Self::BDT => 3,
+            Self::TAI
+            | Self::TDB
+            | Self::UTC
+            | Self::GST
+            | Self::BDT
+            | Self::TCG
+            | Self::TCB
+            | Self::UT1
+            | Self::LTC => 3,
             Self::ET | Self::TT => 2,
         }
     }
 
     /// Returns true if Self is based off a GNSS constellation
     pub const fn is_gnss(&self) -> bool {
-        matches!(self, Self::GPST | Self::GST | Self::BDT | Self::QZSST)
+        matches!(
+            self,
+            Self::GPST | Self::GST | Self::BDT | Self::QZSST | Self::GLONASST | Self::IRNWT
+        )
     }
 
     /// Returns this time scale's reference epoch: Time Scale initialization date,
@@ -140,8 +218,8 @@ impl TimeScale {
     /// This is used to compute the Gregorian date representations in any time scale.
     pub(crate) const fn prime_epoch_offset(self) -> Duration {
         match self {
-            TimeScale::ET | TimeScale::TDB => {
-                // Both ET and TDB are defined at J2000, which is 2000-01-01 12:00:00 and there were only 36524 days in the 20th century.
+            TimeScale::ET | TimeScale::TDB | TimeScale::TCB => {
+                // ET, TDB and TCB are all defined at J2000, which is 2000-01-01 12:00:00 and there were only 36524 days in the 20th century.
                 // Hence, this math is the output of (Unit.Century*1 + Unit.Hour*12 - Unit.Day*1).to_parts() via Hifitime in Python.
                 Duration {
                     centuries: 0,
@@ -152,7 +230,7 @@ impl TimeScale {
                 centuries: 0,
                 nanoseconds: 2_524_953_619_000_000_000,
             },
-            TimeScale::GST => Duration {
+            TimeScale::GST | TimeScale::IRNWT => Duration {
                 centuries: 0,
                 nanoseconds: 3_144_268_819_000_000_000,
             },
@@ -160,6 +238,11 @@ impl TimeScale {
                 centuries: 1,
                 nanoseconds: 189_302_433_000_000_000,
             },
+            // GLONASST is defined at 1996-01-01 midnight, 35063 calendar days after 1900-01-01 (leap seconds are accounted for as in UTC).
+            TimeScale::GLONASST => Duration {
+                centuries: 0,
+                nanoseconds: 3_029_443_200_000_000_000,
+            },
             _ => Duration::ZERO,
         }
     }
@@ -175,12 +258,15 @@ impl TimeScale {
 impl TimeScale {
     /// Returns true if self takes leap seconds into account
     pub const fn uses_leap_seconds(&self) -> bool {
-        matches!(self, Self::UTC)
+        matches!(
+            self,
+            Self::UTC | Self::GLONASST | Self::UTCSLS | Self::UTCSMEAR
+        )
     }
 }
 
 /// Allows conversion of a TimeSystem into a u8
-/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7; QZSST: 8;
+/// Mapping: TAI: 0; TT: 1; ET: 2; TDB: 3; UTC: 4; GPST: 5; GST: 6; BDT: 7; QZSST: 8; TCG: 9; TCB: 10; UT1: 11; GLONASST: 12; IRNWT: 13; LTC: 14; UTCSLS: 15; UTCSMEAR: 16;
 impl From<TimeScale> for u8 {
     fn from(ts: TimeScale) -> Self {
         match ts {
@@ -193,12 +279,20 @@ impl From<TimeScale> for u8 {
             TimeScale::GST => 6,
             TimeScale::BDT => 7,
             TimeScale::QZSST => 8,
+            TimeScale::TCG => 9,
+            TimeScale::TCB => 10,
+            TimeScale::UT1 => 11,
+            TimeScale::GLONASST => 12,
+            TimeScale::IRNWT => 13,
+            TimeScale::LTC => 14,
+            TimeScale::UTCSLS => 15,
+            TimeScale::UTCSMEAR => 16,
         }
     }
 }
 
 /// Allows conversion of a u8 into a TimeSystem.
-/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; 8: QZSST; anything else: TAI
+/// Mapping: 1: TT; 2: ET; 3: TDB; 4: UTC; 5: GPST; 6: GST; 7: BDT; 8: QZSST; 9: TCG; 10: TCB; 11: UT1; 12: GLONASST; 13: IRNWT; 14: LTC; 15: UTCSLS; 16: UTCSMEAR; anything else: TAI
 impl From<u8> for TimeScale {
     fn from(val: u8) -> Self {
         match val {
@@ -210,6 +304,14 @@ impl From<u8> for TimeScale {
             6 => Self::GST,
             7 => Self::BDT,
             8 => Self::QZSST,
+            9 => Self::TCG,
+            10 => Self::TCB,
+            11 => Self::UT1,
+            12 => Self::GLONASST,
+            13 => Self::IRNWT,
+            14 => Self::LTC,
+            15 => Self::UTCSLS,
+            16 => Self::UTCSMEAR,
             _ => Self::TAI,
         }
     }
@@ -234,8 +336,8 @@ mod unit_test_timescale {
         for ts_u8 in 0..u8::MAX {
             let ts = TimeScale::from(ts_u8);
             let ts_u8_back: u8 = ts.into();
-            // If the u8 is greater than 5, it isn't valid and necessarily encoded as TAI.
-            if ts_u8 < 9 {
+            // If the u8 is greater than 16, it isn't valid and necessarily encoded as TAI.
+            if ts_u8 < 17 {
                 assert_eq!(ts_u8_back, ts_u8, "got {ts_u8_back} want {ts_u8}");
             } else {
                 assert_eq!(ts, TimeScale::TAI);
@@ -3,9 +3,11 @@ extern crate core;
 
 use hifitime::{
     is_gregorian_valid, Duration, Epoch, HifitimeError, ParsingError, TimeScale, TimeUnits, Unit,
-    Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, DAYS_PER_YEAR, GPST_REF_EPOCH, GST_REF_EPOCH,
-    J1900_REF_EPOCH, J2000_REF_EPOCH, JD_J2000, MJD_J1900, MJD_J2000, MJD_OFFSET,
-    SECONDS_BDT_TAI_OFFSET, SECONDS_GPS_TAI_OFFSET, SECONDS_GST_TAI_OFFSET, SECONDS_PER_DAY,
+    Weekday, BDT_REF_EPOCH, DAYS_GPS_TAI_OFFSET, DAYS_PER_YEAR, GLONASST_REF_EPOCH, GPST_REF_EPOCH,
+    GST_REF_EPOCH, IAU_1977_REF_EPOCH, IAU_L_G, IRNWT_REF_EPOCH, J1900_REF_EPOCH, J2000_REF_EPOCH,
+    JD_J2000, LTC_TT_RATE, MJD_J1900, MJD_J2000, MJD_OFFSET, SECONDS_BDT_TAI_OFFSET,
+    SECONDS_GPS_TAI_OFFSET, SECONDS_GST_TAI_OFFSET, SECONDS_IRNWT_TAI_OFFSET, SECONDS_PER_DAY,
+    SECONDS_PER_SOL,
 };
 
 use hifitime::efmt::{Format, Formatter};
@@ -513,6 +515,59 @@ fn galileo_time_scale() {
     );
 }
 
+#[test]
+fn navic_time_scale() {
+    let now = Epoch::from_gregorian_tai_hms(2019, 8, 24, 3, 49, 9);
+    let nanos = now.to_irnwt_nanoseconds().unwrap();
+    assert_eq!(
+        Epoch::from_irnwt_nanoseconds(nanos),
+        now,
+        "To/from (recip.) IRNWT nanoseconds failed"
+    );
+    assert!(
+        (now.to_tai_seconds() - SECONDS_IRNWT_TAI_OFFSET - now.to_irnwt_seconds()).abs() < EPSILON
+    );
+
+    let irnwt_epoch = Epoch::from_tai_seconds(SECONDS_IRNWT_TAI_OFFSET);
+    assert_eq!(irnwt_epoch, Epoch::from_irnwt_days(0.0));
+    assert_eq!(irnwt_epoch, Epoch::from_irnwt_seconds(0.0));
+    assert_eq!(irnwt_epoch, Epoch::from_irnwt_nanoseconds(0));
+    assert_eq!(irnwt_epoch, IRNWT_REF_EPOCH);
+    assert_eq!(IRNWT_REF_EPOCH, GST_REF_EPOCH);
+    assert_eq!(format!("{:x}", IRNWT_REF_EPOCH), "1999-08-22T00:00:19 TAI");
+    assert_eq!(
+        format!("{}", Epoch::from_irnwt_seconds(0.0)),
+        "1999-08-22T00:00:00 IRNWT"
+    );
+    assert!(irnwt_epoch.to_irnwt_days().abs() < EPSILON);
+
+    // IRNWT is aligned with GST, and offset by a constant from the other GNSS time scales.
+    let gpst_irnwt_offset = IRNWT_REF_EPOCH.to_tai_duration() - GPST_REF_EPOCH.to_tai_duration();
+    for epoch in [
+        now,
+        Epoch::from_gregorian_utc_hms(2005, 12, 31, 12, 0, 0),
+        Epoch::from_gregorian_utc_hms(2023, 3, 14, 15, 9, 26),
+    ] {
+        let irnwt = epoch.to_time_scale(TimeScale::IRNWT);
+        assert_eq!(irnwt.duration, epoch.to_gst_duration());
+        assert_eq!(irnwt.to_gpst_duration() - irnwt.duration, gpst_irnwt_offset);
+        for ts in [
+            TimeScale::GPST,
+            TimeScale::GST,
+            TimeScale::BDT,
+            TimeScale::QZSST,
+            TimeScale::UTC,
+        ] {
+            let converted = irnwt.to_time_scale(ts);
+            assert_eq!(converted, epoch, "{ts}");
+            assert_eq!(
+                converted.to_time_scale(TimeScale::IRNWT).duration,
+                irnwt.duration
+            );
+        }
+    }
+}
+
 #[test]
 fn beidou_time_scale() {
     let now = Epoch::from_gregorian_tai_hms(2019, 8, 24, 3, 49, 9);
@@ -558,6 +613,66 @@ fn beidou_time_scale() {
     );
 }
 
+#[test]
+fn glonass_time_scale() {
+    let now = Epoch::from_gregorian_tai_hms(2019, 8, 24, 3, 49, 9);
+    let nanos = now.to_glonasst_nanoseconds().unwrap();
+    assert_eq!(
+        Epoch::from_glonasst_nanoseconds(nanos),
+        now,
+        "To/from (recip.) GLONASST nanoseconds failed"
+    );
+
+    let glonasst_epoch = Epoch::from_glonasst_seconds(0.0);
+    assert_eq!(glonasst_epoch, Epoch::from_glonasst_days(0.0));
+    assert_eq!(glonasst_epoch, Epoch::from_glonasst_nanoseconds(0));
+    assert_eq!(glonasst_epoch, GLONASST_REF_EPOCH);
+    assert_eq!(
+        glonasst_epoch,
+        Epoch::from_gregorian_utc_hms(1995, 12, 31, 21, 0, 0)
+    );
+
+    assert_eq!(format!("{glonasst_epoch}"), "1996-01-01T00:00:00 GLONASST");
+    assert_eq!(format!("{glonasst_epoch:?}"), "1995-12-31T21:00:00 UTC");
+    assert_eq!(format!("{glonasst_epoch:x}"), "1995-12-31T21:00:29 TAI");
+
+    // GLONASST is UTC + 3 hours, and follows leap seconds.
+    let utc = Epoch::from_gregorian_utc_hms(2022, 6, 15, 10, 30, 0);
+    assert_eq!(
+        format!("{}", utc.to_time_scale(TimeScale::GLONASST)),
+        "2022-06-15T13:30:00 GLONASST"
+    );
+    let before_leap = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    let after_leap = Epoch::from_gregorian_utc_at_noon(2017, 1, 1);
+    assert_eq!(
+        after_leap.to_tai_duration() - before_leap.to_tai_duration(),
+        1 * Unit::Day + 1 * Unit::Second
+    );
+    assert_eq!(
+        after_leap.to_glonasst_duration() - before_leap.to_glonasst_duration(),
+        1 * Unit::Day
+    );
+
+    // Four-year interval N4 and day number NT
+    assert_eq!(glonasst_epoch.to_glonasst_n4_nt(), (1, 1, Duration::ZERO));
+    assert_eq!(
+        utc.to_glonasst_n4_nt(),
+        (7, 897, 13 * Unit::Hour + 30 * Unit::Minute)
+    );
+    assert_eq!(
+        Epoch::from_glonasst_n4_nt(7, 897, 13 * Unit::Hour + 30 * Unit::Minute),
+        utc
+    );
+    // Last day of the first interval
+    let last_day = Epoch::from_glonasst_n4_nt(1, 1461, 12 * Unit::Hour);
+    assert_eq!(format!("{last_day}"), "1999-12-31T12:00:00 GLONASST");
+    assert_eq!(last_day.to_glonasst_n4_nt(), (1, 1461, 12 * Unit::Hour));
+    // First day of the N4 = 7 interval, at midnight Moscow time
+    let start_2020 = Epoch::from_gregorian_utc_hms(2019, 12, 31, 21, 0, 0);
+    assert_eq!(start_2020.to_glonasst_n4_nt(), (7, 1, Duration::ZERO));
+    assert_eq!(Epoch::from_glonasst_n4_nt(7, 1, Duration::ZERO), start_2020);
+}
+
 #[test]
 fn unix() {
     // Continuous check that the system time as reported by this machine is within millisecond accuracy of what we compute
@@ -601,9 +716,10 @@ fn unix() {
         format!("{}", unix_epoch.to_time_scale(TimeScale::UTC)),
         "1970-01-01T00:00:00 UTC"
     );
+    // TAI - UTC drifted before 1972, cf. SOFA `iauDat`: 4.2131700 + (40587 - 39126) × 0.002592 = 8.000082 seconds.
     assert_eq!(
         format!("{:x}", unix_epoch.to_time_scale(TimeScale::TAI)),
-        "1970-01-01T00:00:00 TAI"
+        "1970-01-01T00:00:08.000082000 TAI"
     );
     // Print as UNIX seconds
     assert_eq!(format!("{:p}", unix_epoch), "0");
@@ -638,7 +754,7 @@ fn naif_spice_et_tdb_verification() {
     // The maximum error due to small perturbations accounted for in ESA algorithm but not SPICE algorithm.
     let max_tdb_et_err = 32 * Unit::Microsecond;
     // Prior to 01 JAN 1972, IERS claims that there is no leap second at all but SPICE claims that there are nine (9) leap seconds
-    // between TAI and UTC. Hifitime also claims that there are zero leap seconds (to ensure correct computation of UNIX time at its reference time).
+    // between TAI and UTC. Hifitime follows SOFA `iauDat` instead, where TAI - UTC drifted from 1.4178180 seconds in 1960 to 9.890946 seconds in 1971.
     let spice_utc_tai_ls_err = 9.0;
     // SPICE will only output up to 6 digits for the JDE computation. This is likely due to the precision limitation of the `double`s type.
     // This means that a SPICE JDE is precise to 0.008 seconds, whereas a JDE in Hifitime maintains its nanosecond precision.
@@ -667,13 +783,17 @@ fn naif_spice_et_tdb_verification() {
         );
 
         // Test ET computation
-        let extra_seconds = if epoch.leap_seconds_iers() == 0 {
-            spice_utc_tai_ls_err
+        // Before 1972, the fractional TAI - UTC makes the ET seconds exact only to their last bit.
+        let (extra_seconds, et_err) = if epoch.leap_seconds_iers() == 0 {
+            (
+                spice_utc_tai_ls_err - epoch.leap_seconds(false).unwrap_or(0.0),
+                et_s.abs() * EPSILON,
+            )
         } else {
-            0.0
+            (0.0, EPSILON)
         };
         assert!(
-            (epoch.to_et_seconds() - et_s + extra_seconds).abs() < EPSILON,
+            (epoch.to_et_seconds() - et_s + extra_seconds).abs() <= et_err,
             "{} failed ET test",
             epoch
         );
@@ -844,6 +964,436 @@ fn spice_et_tdb() {
     assert!((2455964.9739931 - sp_ex.to_jde_tdb_days()).abs() < max_tdb_et_err.to_seconds());
 }
 
+#[test]
+fn tcg_tcb() {
+    // TT, TCG, and TCB coincide on 1977-01-01T00:00:32.184 TT, i.e. 1977 January 01 midnight TAI.
+    let t0 = IAU_1977_REF_EPOCH;
+    assert_eq!(t0.to_tcg_duration(), t0.to_tt_duration());
+    // At T0, TCB and TDB only differ by TDB0.
+    assert!(
+        (t0.to_tcb_duration() - t0.to_tdb_duration() - 65.5 * Unit::Microsecond).abs()
+            < 1 * Unit::Nanosecond
+    );
+
+    // Validated against SOFA iauTttcg(2453750.5, 0.892482639) = (2453750.5, 0.8924900312508587113)
+    let tt_epoch = Epoch::from_gregorian(2006, 1, 15, 21, 25, 10, 500_009_600, TimeScale::TT);
+    let tcg_minus_tt = tt_epoch.to_tcg_duration() - tt_epoch.to_tt_duration();
+    assert!(
+        (tcg_minus_tt - 0.638_690_474 * Unit::Second).abs() < 2 * Unit::Nanosecond,
+        "TCG - TT = {tcg_minus_tt}"
+    );
+    assert_eq!(
+        tt_epoch.to_time_scale(TimeScale::TCG).time_scale,
+        TimeScale::TCG
+    );
+    assert!(
+        (Epoch::from_tcg_duration(tt_epoch.to_tcg_duration()) - tt_epoch).abs()
+            < 2 * Unit::Nanosecond
+    );
+
+    // Validated against SOFA iauTdbtcb(2453750.5, 0.892855137) = (2453750.5, 0.8930195997253656716)
+    // The SOFA output is only precise to a fraction of a microsecond when expressed in days.
+    let tdb_epoch = Epoch::from_gregorian(2006, 1, 15, 21, 25, 42, 683_836_800, TimeScale::TDB);
+    let tcb_minus_tdb = tdb_epoch.to_tcb_duration() - tdb_epoch.to_tdb_duration();
+    assert!(
+        (tcb_minus_tdb - 14.209_579_472 * Unit::Second).abs() < 1 * Unit::Microsecond,
+        "TCB - TDB = {tcb_minus_tdb}"
+    );
+    assert!(
+        (Epoch::from_tcb_duration(tdb_epoch.to_tcb_duration()) - tdb_epoch).abs()
+            < 2 * Unit::Nanosecond
+    );
+
+    // Julian dates
+    assert!((tt_epoch.to_jde_tcg_days() - 2_453_751.392_490_031).abs() < 1e-9);
+    assert!((tdb_epoch.to_jde_tcb_days() - 2_453_751.393_019_6).abs() < 1e-9);
+    assert!(
+        (Epoch::from_jde_tcb(tdb_epoch.to_jde_tcb_days()) - tdb_epoch).abs()
+            < 100 * Unit::Microsecond
+    );
+    assert!((Epoch::from_mjd_tcg(51_544.5).to_mjd_tcg_days() - 51_544.5).abs() < EPSILON);
+    assert!((Epoch::from_mjd_tcb(51_544.5).to_mjd_tcb_days() - 51_544.5).abs() < EPSILON);
+    assert_eq!(
+        Epoch::from_mjd_tcb(51_544.5).to_tcb_duration(),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn lunar_time() {
+    use core::str::FromStr;
+    // LTC coincides with TT on 1977-01-01T00:00:32.184 TT.
+    let t0 = IAU_1977_REF_EPOCH;
+    assert_eq!(t0.to_ltc_duration(), t0.to_tt_duration());
+
+    // Ashby & Patla (2024): a clock on the lunar surface gains 56.02 µs per day on a clock on the geoid,
+    // and the periodic term due to the eccentricity of the lunar orbit has an amplitude of about 0.48 µs.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 4, 2);
+    let ltc_minus_tt = |e: Epoch| e.to_ltc_duration() - e.to_tt_duration();
+
+    let daily_gain = ltc_minus_tt(epoch + 1 * Unit::Day) - ltc_minus_tt(epoch);
+    assert!(
+        (daily_gain - 56.02 * Unit::Microsecond).abs() < 150 * Unit::Nanosecond,
+        "daily gain = {daily_gain}"
+    );
+
+    // Over one anomalistic month, the periodic term cancels out.
+    let anomalistic_month = 27.554_55 * Unit::Day;
+    let monthly_gain = ltc_minus_tt(epoch + anomalistic_month) - ltc_minus_tt(epoch);
+    assert!(
+        (monthly_gain - 27.554_55 * 56.02 * Unit::Microsecond).abs() < 10 * Unit::Nanosecond,
+        "monthly gain = {monthly_gain}"
+    );
+
+    // Peak to peak amplitude of the periodic term
+    let (mut min_residual, mut max_residual) = (f64::MAX, f64::MIN);
+    for hour in 0..(28 * 24) {
+        let e = epoch + hour * Unit::Hour;
+        let residual = (ltc_minus_tt(e) - ltc_minus_tt(epoch)).to_seconds()
+            - (e - epoch).to_seconds() * LTC_TT_RATE;
+        min_residual = min_residual.min(residual);
+        max_residual = max_residual.max(residual);
+    }
+    let peak_to_peak = max_residual - min_residual;
+    assert!(
+        (peak_to_peak - 2.0 * 0.478e-6).abs() < 0.02e-6,
+        "peak to peak = {peak_to_peak:e} s"
+    );
+
+    // About 20.5 ms per year
+    let yearly_gain = ltc_minus_tt(epoch + 1 * Unit::Century / 100) - ltc_minus_tt(epoch);
+    assert!((yearly_gain - 20_461.305 * Unit::Microsecond).abs() < 2 * Unit::Microsecond);
+
+    // Reciprocity
+    let ltc = epoch.to_time_scale(TimeScale::LTC);
+    assert_eq!(ltc.time_scale, TimeScale::LTC);
+    assert!((Epoch::from_ltc_duration(ltc.duration) - epoch).abs() < 1 * Unit::Nanosecond);
+    assert!((Epoch::from_ltc_seconds(ltc.to_ltc_seconds()) - epoch).abs() < 1 * Unit::Microsecond);
+    let parsed = Epoch::from_str(&format!("{ltc}")).unwrap();
+    assert_eq!(parsed.time_scale, TimeScale::LTC);
+    assert!((parsed - epoch).abs() < 1 * Unit::Nanosecond);
+}
+
+#[test]
+fn lunar_time_reference() {
+    // The secular rate and periodic term of LTC - TT follow from the physical constants, independently of the hifitime model,
+    // cf. Ashby & Patla (2024), section 4: the rate of TT with respect to TCG (IAU 2000 Resolution B1.9), minus the potential and
+    // kinetic energy of the Moon in the field of the Earth (3 GM_E / 2a for a Keplerian orbit), minus the potential at the lunar surface.
+    const C2: f64 = 299_792_458.0 * 299_792_458.0;
+    // IERS Conventions (2010), Table 1.1
+    const GM_EARTH: f64 = 3.986_004_418e14;
+    // DE430 lunar GM and IAU WGCCRE mean lunar radius
+    const GM_MOON: f64 = 4.902_800_066e12;
+    const MOON_RADIUS: f64 = 1_737.4e3;
+    // Mean semi-major axis and eccentricity of the lunar orbit
+    const MOON_SMA: f64 = 384_399e3;
+    const MOON_ECC: f64 = 0.0549;
+
+    let rate = IAU_L_G - 1.5 * GM_EARTH / (MOON_SMA * C2) - GM_MOON / (MOON_RADIUS * C2);
+    // Ashby & Patla (2024) publish 56.02 µs/day.
+    assert!((rate * 86_400.0 - 56.02e-6).abs() < 0.02e-6, "{rate:e}");
+
+    // Average daily gain over several anomalistic months, such that the periodic term cancels out.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2026, 1, 1);
+    let ltc_minus_tt = |e: Epoch| (e.to_ltc_duration() - e.to_tt_duration()).to_seconds();
+    let span = 10.0 * 27.554_55 * Unit::Day;
+    let mean_rate = (ltc_minus_tt(epoch + span) - ltc_minus_tt(epoch)) / span.to_seconds();
+    assert!(
+        (mean_rate - rate).abs() * 86_400.0 < 0.03e-6,
+        "model rate {:.3} µs/day, physical rate {:.3} µs/day",
+        mean_rate * 86_400.0e6,
+        rate * 86_400.0e6
+    );
+
+    // The periodic term of a Keplerian orbit is 2√(GM a) e sin(E) / c², about 0.48 µs.
+    let amplitude = 2.0 * (GM_EARTH * MOON_SMA).sqrt() * MOON_ECC / C2;
+    let (mut min_residual, mut max_residual) = (f64::MAX, f64::MIN);
+    for hour in 0..(28 * 24) {
+        let e = epoch + hour * Unit::Hour;
+        let residual = ltc_minus_tt(e) - ltc_minus_tt(epoch) - (e - epoch).to_seconds() * mean_rate;
+        min_residual = min_residual.min(residual);
+        max_residual = max_residual.max(residual);
+    }
+    let model_amplitude = (max_residual - min_residual) / 2.0;
+    assert!(
+        (model_amplitude - amplitude).abs() < 0.01e-6,
+        "model amplitude {model_amplitude:e} s, physical amplitude {amplitude:e} s"
+    );
+}
+
+#[test]
+fn mars_sol_date() {
+    // Worked example from NASA GISS Mars24 (cf. Allison & McEwen, 2000)
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2000, 1, 6);
+    assert!((epoch.to_mars_sol_date() - 44_795.999_76).abs() < 1e-5);
+
+    let mtc = epoch.to_mars_coordinated_time();
+    assert!(
+        (mtc.to_unit(Unit::Hour) - 23.994_25).abs() < 1e-5,
+        "MTC = {mtc}"
+    );
+    // Local mean solar time at the prime meridian is MTC, and wraps around at 24 hours.
+    assert_eq!(epoch.to_mars_local_mean_solar_time(0.0), mtc);
+    let lmst = epoch.to_mars_local_mean_solar_time(90.0);
+    assert!(
+        (lmst.to_unit(Unit::Hour) - 5.994_25).abs() < 1e-5,
+        "LMST = {lmst}"
+    );
+    let lmst = epoch.to_mars_local_mean_solar_time(-90.0);
+    assert!(
+        (lmst.to_unit(Unit::Hour) - 17.994_25).abs() < 1e-5,
+        "LMST = {lmst}"
+    );
+
+    // The equation of time is -5.18775 degrees, or -0.34585 Martian hours.
+    let ltst = epoch.to_mars_local_true_solar_time(0.0);
+    assert!(
+        (ltst.to_unit(Unit::Hour) - 23.648_40).abs() < 1e-5,
+        "LTST = {ltst}"
+    );
+
+    // Round trip
+    let msd = epoch.to_mars_sol_date();
+    assert!((Epoch::from_mars_sol_date(msd) - epoch).abs() < 1 * Unit::Microsecond);
+
+    // The Mars Sol Date is defined in TT
+    assert_eq!(
+        Epoch::from_mars_sol_date(44_796.0 - 0.000_962_6),
+        Epoch::from_gregorian_at_midnight(2000, 1, 6, TimeScale::TT)
+    );
+
+    // One sol later, the Mars Sol Date has increased by one.
+    let next_sol = epoch + Duration::SOL;
+    assert!((next_sol.to_mars_sol_date() - msd - 1.0).abs() < 1e-9);
+    assert!((next_sol.to_mars_coordinated_time() - mtc).abs() < 1 * Unit::Microsecond);
+    assert_eq!(Duration::SOL.to_seconds(), SECONDS_PER_SOL);
+}
+
+#[test]
+fn earth_local_solar_time() {
+    // Example 28.b of Meeus, Astronomical Algorithms: the equation of time is +13m42.6s on 1992 October 13 at 0h TD.
+    let epoch = Epoch::from_gregorian_at_midnight(1992, 10, 13, TimeScale::TT);
+    let eot = epoch.equation_of_time();
+    assert!(
+        (eot - 13.minutes() - 42.6.seconds()).abs() < 1.seconds(),
+        "{eot}"
+    );
+    // The sundial is behind the clock by about 14 minutes in mid February.
+    let eot = Epoch::from_gregorian_utc_at_noon(2024, 2, 11).equation_of_time();
+    assert!(
+        (eot + 14.minutes() + 14.seconds()).abs() < 5.seconds(),
+        "{eot}"
+    );
+
+    // Approximating UT1 with UTC, the local mean solar time at Greenwich is the UTC time of day.
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 2, 11, 22, 15, 0);
+    assert_eq!(
+        epoch.to_local_mean_solar_time_from_utc(0.0),
+        22.hours() + 15.minutes()
+    );
+    // Four minutes per degree of longitude, wrapping around at midnight.
+    assert_eq!(epoch.to_local_mean_solar_time_from_utc(30.0), 15.minutes());
+    assert_eq!(
+        epoch.to_local_mean_solar_time_from_utc(-90.0),
+        16.hours() + 15.minutes()
+    );
+    assert_eq!(
+        epoch.to_local_true_solar_time_from_utc(-90.0),
+        16.hours() + 15.minutes() + epoch.equation_of_time()
+    );
+
+    // Next 10:30 descending node of a sun-synchronous orbit over 120 degrees East
+    let node = epoch.next_local_mean_solar_time_from_utc(120.0, 10.hours() + 30.minutes());
+    assert_eq!(node, Epoch::from_gregorian_utc_hms(2024, 2, 12, 2, 30, 0));
+    assert_eq!(
+        node.next_local_mean_solar_time_from_utc(120.0, 10.hours() + 30.minutes()),
+        node
+    );
+
+    let node = epoch.next_local_true_solar_time_from_utc(120.0, 10.hours() + 30.minutes());
+    assert!(node >= epoch);
+    assert!(node - epoch < 1.days());
+    let ltst = node.to_local_true_solar_time_from_utc(120.0);
+    assert!(
+        (ltst - 10.hours() - 30.minutes()).abs() < 1.microseconds(),
+        "{ltst}"
+    );
+    // The true solar time is late in February, so the node is after the mean solar time one.
+    assert!((node - Epoch::from_gregorian_utc_hms(2024, 2, 12, 2, 44, 14)).abs() < 5.seconds());
+
+    // Across a leap second, the mean solar time of the next day is reached one second later.
+    let epoch = Epoch::from_gregorian_utc_at_noon(2016, 12, 31);
+    let noon = epoch.next_local_mean_solar_time_from_utc(0.0, 12.hours());
+    assert_eq!(noon, epoch);
+    let noon = (epoch + 1.seconds()).next_local_mean_solar_time_from_utc(0.0, 12.hours());
+    assert_eq!(noon, Epoch::from_gregorian_utc_at_noon(2017, 1, 1));
+    assert_eq!(
+        noon.to_tai_duration() - epoch.to_tai_duration(),
+        1.days() + 1.seconds()
+    );
+}
+
+#[test]
+fn tdb_models() {
+    use hifitime::{TdbModel, TdbObserver};
+
+    // Fairhead & Bretagnon, at the Goldstone complex
+    let goldstone = TdbObserver::new(243.11, 5203.997, 3677.052);
+    let models = [
+        TdbModel::Fast,
+        TdbModel::FairheadBretagnon(None),
+        TdbModel::FairheadBretagnon(Some(goldstone)),
+    ];
+
+    for year in [1960, 1985, 2000, 2024, 2050] {
+        for month in [1, 5, 9] {
+            let epoch = Epoch::from_gregorian_utc_hms(year, month, 17, 6, 30, 0);
+            // The fast path remains the default
+            assert_eq!(TdbModel::default(), TdbModel::Fast);
+            assert_eq!(
+                epoch.to_tdb_duration_with_model(TdbModel::Fast),
+                epoch.to_tdb_duration()
+            );
+
+            // The fast model is accurate to a few tens of microseconds.
+            let fast = TdbModel::Fast.tdb_minus_tt(epoch);
+            let series = TdbModel::FairheadBretagnon(None).tdb_minus_tt(epoch);
+            assert!(
+                (fast - series).abs() < 35 * Unit::Microsecond,
+                "{epoch}: fast {fast} vs series {series}"
+            );
+
+            // The topocentric terms are at the microsecond level for an observer on the Earth's surface.
+            let topocentric =
+                TdbModel::FairheadBretagnon(Some(goldstone)).tdb_minus_tt(epoch) - series;
+            assert!(topocentric.abs() < 2500 * Unit::Nanosecond);
+
+            for model in models {
+                let tdb = epoch.to_tdb_duration_with_model(model);
+                assert_eq!(epoch.to_tdb_seconds_with_model(model), tdb.to_seconds());
+                let rtn = Epoch::from_tdb_duration_with_model(tdb, model);
+                assert!(
+                    (rtn - epoch).abs() <= 1 * Unit::Nanosecond,
+                    "{model:?}: {epoch} != {rtn}"
+                );
+            }
+        }
+    }
+}
+#[test]
+#[test]
+fn smeared_utc() {
+    use core::str::FromStr;
+
+    let tai = |y, m, d, hh, mm, ss, nanos| {
+        Epoch::from_gregorian(y, m, d, hh, mm, ss, nanos, TimeScale::TAI)
+    };
+    let sls = |y, m, d, hh, mm, ss, nanos| {
+        Epoch::from_gregorian(y, m, d, hh, mm, ss, nanos, TimeScale::UTCSLS)
+    };
+    let smear = |y, m, d, hh, mm, ss, nanos| {
+        Epoch::from_gregorian(y, m, d, hh, mm, ss, nanos, TimeScale::UTCSMEAR)
+    };
+
+    // Away from leap seconds, the smeared time scales match UTC.
+    let epoch = Epoch::from_gregorian_utc_hms(2020, 6, 1, 12, 34, 56);
+    for ts in [TimeScale::UTCSLS, TimeScale::UTCSMEAR] {
+        assert_eq!(
+            epoch.to_time_scale(ts).duration,
+            epoch.to_time_scale(TimeScale::UTC).duration
+        );
+    }
+
+    // Leap second at the end of 2016, where TAI - UTC went from 36 to 37 seconds.
+    // UTC-SLS smears it over the last 1000 seconds of the day.
+    assert_eq!(
+        tai(2016, 12, 31, 23, 43, 56, 0).to_time_scale(TimeScale::UTCSLS),
+        sls(2016, 12, 31, 23, 43, 20, 0)
+    );
+    assert_eq!(
+        tai(2016, 12, 31, 23, 52, 16, 500_000_000).to_time_scale(TimeScale::UTCSLS),
+        sls(2016, 12, 31, 23, 51, 40, 0)
+    );
+    assert_eq!(
+        tai(2017, 1, 1, 0, 0, 37, 0).to_time_scale(TimeScale::UTCSLS),
+        sls(2017, 1, 1, 0, 0, 0, 0)
+    );
+    // Each UTC-SLS second lasts 1.001 SI seconds during the smear.
+    assert_eq!(
+        sls(2016, 12, 31, 23, 59, 59, 0) - sls(2016, 12, 31, 23, 59, 58, 0),
+        1 * Unit::Second
+    );
+    assert_eq!(
+        sls(2016, 12, 31, 23, 59, 59, 0).to_time_scale(TimeScale::TAI)
+            - sls(2016, 12, 31, 23, 59, 58, 0).to_time_scale(TimeScale::TAI),
+        1001 * Unit::Millisecond
+    );
+
+    // The 24 hour smear spreads it from noon to noon.
+    assert_eq!(
+        tai(2016, 12, 31, 12, 0, 36, 0).to_time_scale(TimeScale::UTCSMEAR),
+        smear(2016, 12, 31, 12, 0, 0, 0)
+    );
+    assert_eq!(
+        tai(2017, 1, 1, 0, 0, 36, 500_000_000).to_time_scale(TimeScale::UTCSMEAR),
+        smear(2017, 1, 1, 0, 0, 0, 0)
+    );
+    assert_eq!(
+        tai(2017, 1, 1, 12, 0, 37, 0).to_time_scale(TimeScale::UTCSMEAR),
+        smear(2017, 1, 1, 12, 0, 0, 0)
+    );
+
+    // Exact round trips from the smeared time scales, and within one nanosecond from TAI.
+    for ts in [TimeScale::UTCSLS, TimeScale::UTCSMEAR] {
+        let start = Epoch::from_gregorian(2016, 12, 31, 11, 0, 0, 0, ts);
+        for step in 0..1_000 {
+            let epoch = start + step * 97_777_777 * Unit::Microsecond / 1_000;
+            let epoch = Epoch::from_duration(epoch.duration + step * Unit::Nanosecond, ts);
+            let rtn = epoch.to_time_scale(TimeScale::TAI).to_time_scale(ts);
+            assert_eq!(rtn.duration, epoch.duration, "{epoch}");
+
+            let tai_epoch = epoch.to_time_scale(TimeScale::TAI) + 1 * Unit::Nanosecond;
+            let rtn = tai_epoch.to_time_scale(ts).to_time_scale(TimeScale::TAI);
+            assert!(
+                (rtn - tai_epoch).abs() <= 1 * Unit::Nanosecond,
+                "{tai_epoch}"
+            );
+        }
+    }
+
+    // Display and parsing
+    let epoch = sls(2016, 12, 31, 23, 51, 40, 0);
+    assert_eq!(format!("{epoch}"), "2016-12-31T23:51:40 UTC-SLS");
+    assert_eq!(
+        Epoch::from_str("2016-12-31T23:51:40 UTC-SLS").unwrap(),
+        epoch
+    );
+    let epoch = smear(2017, 1, 1, 0, 0, 0, 0);
+    assert_eq!(format!("{epoch}"), "2017-01-01T00:00:00 UTC-SMEAR");
+    assert_eq!(
+        Epoch::from_str("2017-01-01T00:00:00 UTC-SMEAR").unwrap(),
+        epoch
+    );
+}
+fn ut1_without_provider() {
+    use core::str::FromStr;
+    // Without any installed UT1 provider, UT1 is assumed to match UTC.
+    let utc = Epoch::from_gregorian_utc(2022, 1, 3, 3, 5, 6, 789_100_000);
+    let ut1 = utc.to_time_scale(TimeScale::UT1);
+    assert_eq!(ut1.time_scale, TimeScale::UT1);
+    assert_eq!(ut1.duration, utc.duration);
+    assert_eq!(format!("{ut1}"), "2022-01-03T03:05:06.789100000 UT1");
+    assert_eq!(ut1, utc);
+    assert_eq!(
+        Epoch::from_str("2022-01-03T03:05:06.7891 UT1").unwrap(),
+        utc
+    );
+    assert_eq!(
+        Epoch::from_str("MJD 59582.5 UT1").unwrap(),
+        Epoch::from_gregorian_utc_at_noon(2022, 1, 3)
+    );
+}
+
 #[test]
 fn test_from_str() {
     use core::str::FromStr;
@@ -856,6 +1406,8 @@ fn test_from_str() {
     assert!(Epoch::from_str("2017-01-14 00:31:55 TT").is_ok());
     assert!(Epoch::from_str("2017-01-14 00:31:55 ET").is_ok());
     assert!(Epoch::from_str("2017-01-14 00:31:55 TDB").is_ok());
+    assert!(Epoch::from_str("2017-01-14 00:31:55 TCG").is_ok());
+    assert!(Epoch::from_str("2017-01-14 00:31:55 TCB").is_ok());
 
     let jde = 2_452_312.500_372_511;
     let to_tdb = Epoch::from_str("JD 2452312.500372511 TDB").unwrap();
@@ -1121,14 +1673,254 @@ fn test_leap_seconds_iers() {
         epoch_from_utc_greg.duration_in_year(),
         (31 + 29 + 31 + 30 + 31 + 30) * Unit::Day - Unit::Second
     );
-    // Just after it.
+    // At the following TAI midnight, which is still before the leap second in UTC.
     let epoch_from_utc_greg1 = Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 0);
     assert_eq!(epoch_from_utc_greg.leap_seconds_iers(), 10);
-    assert_eq!(epoch_from_utc_greg1.leap_seconds_iers(), 11);
+    assert_eq!(epoch_from_utc_greg1.leap_seconds_iers(), 10);
+    // In UTC, the offset changes at midnight.
+    assert_eq!(
+        Epoch::from_gregorian_utc_hms(1972, 6, 30, 23, 59, 59).leap_seconds_iers(),
+        10
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_hms(1972, 7, 1, 0, 0, 0).leap_seconds_iers(),
+        11
+    );
+    // In TAI, the offset only changes at the end of the leap second, i.e. at 1972-07-01T00:00:00 UTC.
+    assert_eq!(
+        Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 10).leap_seconds_iers(),
+        10
+    );
+    assert_eq!(
+        Epoch::from_gregorian_tai_hms(1972, 7, 1, 0, 0, 11).leap_seconds_iers(),
+        11
+    );
+}
+
+#[test]
+fn test_leap_seconds_sofa_drift() {
+    // Reference values computed with iauDat of SOFA, which only uses the date in UTC.
+    let cases = [
+        ((1960, 1, 1, 0), 0.943_482),
+        ((1961, 1, 1, 0), 1.422_818),
+        ((1962, 1, 1, 0), 1.845_858),
+        ((1965, 6, 15, 12), 3.854_618),
+        ((1968, 2, 1, 0), 6.185_682),
+        ((1971, 12, 31, 12), 9.890_946),
+    ];
+    for ((year, month, day, hour), expected) in cases {
+        let epoch = Epoch::from_gregorian_utc(year, month, day, hour, 0, 0, 0);
+        let delta_at = epoch.leap_seconds(false).unwrap();
+        assert!(
+            (delta_at - expected).abs() < 1e-9,
+            "{epoch}: got {delta_at} expected {expected}"
+        );
+        // Before 1972, UTC does not account for these offsets.
+        assert_eq!(epoch.leap_seconds(true), None);
+
+        // From TAI, the UTC date is found from the drift itself.
+        let tai = Epoch::from_tai_duration(epoch.to_utc_duration() + expected * Unit::Second);
+        assert!((tai.leap_seconds(false).unwrap() - expected).abs() < 1e-9);
+    }
+
+    // UTC conversions account for the drift: iauDat(1965, 10, 1, 0.5) = 3.8401300 + (39034.5 - 38761) × 0.001296 = 4.194586 seconds.
+    let utc = Epoch::from_gregorian_utc_hms(1965, 10, 1, 12, 0, 0);
+    let tai = Epoch::from_gregorian_tai(1965, 10, 1, 12, 0, 4, 194_586_000);
+    assert_eq!(utc.to_time_scale(TimeScale::TAI), tai);
+    assert_eq!(utc, tai);
+    assert_eq!(tai.to_time_scale(TimeScale::UTC), utc);
+    assert_eq!(tai.to_gregorian_utc(), (1965, 10, 1, 12, 0, 0, 0));
+    assert_eq!(format!("{tai:x}"), "1965-10-01T12:00:04.194586000 TAI");
+
+    // UTC was not defined before 1960.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(1959, 12, 31).leap_seconds(false),
+        None
+    );
+    // No drift after 1972.
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_noon(1980, 6, 1).leap_seconds(false),
+        Some(19.0)
+    );
 }
 
+#[test]
+fn test_leap_second_instants() {
+    use core::str::FromStr;
+    use hifitime::efmt::consts::ISO8601;
+    use hifitime::leap_seconds::LatestLeapSeconds;
+
+    // Every IERS leap second, skipping the initial offset of 1972 which is not one.
+    let mut count = 0;
+    for leap_second in LatestLeapSeconds::default()
+        .filter(|leap_second| leap_second.announced_by_iers)
+        .skip(1)
+    {
+        count += 1;
+        let midnight = Epoch::from_utc_seconds(leap_second.timestamp_tai_s);
+        let (year, month, day, _, _, _, _) = (midnight - Unit::Day).to_gregorian_utc();
+        assert!(is_gregorian_valid(year, month, day, 23, 59, 60, 0));
+
+        // Decompose: the leap second ends at that midnight, when TAI - UTC changes.
+        let start = Epoch::from_gregorian_utc(year, month, day, 23, 59, 60, 0);
+        let half = Epoch::from_gregorian_utc(year, month, day, 23, 59, 60, 500_000_000);
+        assert_eq!(start.time_scale, TimeScale::TAI);
+        assert_eq!(start + Unit::Second, midnight);
+        assert_eq!(half + Unit::Millisecond * 500, midnight);
+        assert_eq!(
+            start - Unit::Second,
+            Epoch::from_gregorian_utc(year, month, day, 23, 59, 59, 0)
+        );
+        assert!(start.is_within_leap_second());
+        assert!(half.is_within_leap_second());
+        assert!(!(start - Unit::Nanosecond).is_within_leap_second());
+        assert!(!(start + Unit::Second).is_within_leap_second());
+        assert_eq!(start.to_gregorian_utc(), (year, month, day, 23, 59, 60, 0));
+        assert_eq!(
+            half.to_gregorian_utc(),
+            (year, month, day, 23, 59, 60, 500_000_000)
+        );
+        assert_eq!(
+            (start - Unit::Nanosecond).to_gregorian_utc(),
+            (year, month, day, 23, 59, 59, 999_999_999)
+        );
+        // Within the leap second, TAI - UTC is the previous offset.
+        assert_eq!(half.leap_seconds(true), Some(leap_second.delta_at - 1.0));
+        assert_eq!(
+            (half + Unit::Millisecond * 500).leap_seconds(true),
+            Some(leap_second.delta_at)
+        );
+        // Any other continuous time scale is within the leap second too.
+        assert!(half.to_time_scale(TimeScale::GPST).is_within_leap_second());
+        assert_eq!(
+            half.to_time_scale(TimeScale::TT).to_gregorian_utc(),
+            (year, month, day, 23, 59, 60, 500_000_000)
+        );
+
+        // Format
+        let expected = format!("{year:04}-{month:02}-{day:02}T23:59:60.500000000 UTC");
+        assert_eq!(half.to_gregorian_str(TimeScale::UTC), expected);
+        assert_eq!(format!("{half:?}"), expected);
+        let formatted = format!(
+            "{}",
+            Formatter::to_time_scale(half, ISO8601, TimeScale::UTC)
+        );
+        assert_eq!(
+            formatted,
+            format!("{year:04}-{month:02}-{day:02}T23:59:60.500000000 UTC")
+        );
+        let seconds = Format::from_str("%H:%M:%S").unwrap();
+        assert_eq!(
+            format!(
+                "{}",
+                Formatter::to_time_scale(start, seconds, TimeScale::UTC)
+            ),
+            "23:59:60"
+        );
+
+        // Parse
+        assert_eq!(Epoch::from_str(&expected).unwrap(), half);
+        assert_eq!(Epoch::from_gregorian_str(&expected).unwrap(), half);
+        assert_eq!(ISO8601.parse(&formatted).unwrap(), half);
+    }
+    assert_eq!(count, 27);
+
+    // Only leap second days have a 61st second.
+    assert!(Epoch::maybe_from_gregorian_utc(2017, 12, 31, 23, 59, 60, 0).is_err());
+    assert!(!Epoch::from_gregorian_utc_hms(2017, 1, 1, 0, 0, 0).is_within_leap_second());
+    // The UTC reading of the leap second is that of the following second.
+    let half = Epoch::from_gregorian_utc(2016, 12, 31, 23, 59, 60, 500_000_000);
+    assert_eq!(
+        format!("{}", half.to_time_scale(TimeScale::UTC)),
+        "2017-01-01T00:00:00.500000000 UTC"
+    );
+}
 #[cfg(feature = "std")]
 #[test]
+fn test_delta_t_estimate() {
+    // Values of the polynomials of Espenak & Meeus at the start of their ranges
+    let cases = [
+        (-1000, -20.0 + 32.0 * 28.2_f64.powi(2)),
+        (0, 10_583.6),
+        (1000, 1_574.2),
+        (1700, 8.83),
+        (1900, -2.79),
+        (1950, 29.07),
+    ];
+    for (year, expected) in cases {
+        let epoch = Epoch::from_gregorian_at_midnight(year, 1, 1, TimeScale::TT);
+        let delta_t = epoch.delta_t_estimate().to_seconds();
+        // The decimal year is counted in Julian years, hence a small offset from the Gregorian date.
+        assert!(
+            (delta_t - expected).abs() < 2.0,
+            "{year}: got {delta_t} expected {expected}"
+        );
+    }
+
+    // Since 1960, the polynomials are within 0.1 s of the observed values of ΔT on January 1st.
+    for (year, observed) in [
+        (1960, 33.15),
+        (1970, 40.18),
+        (1980, 50.54),
+        (1990, 56.86),
+        (2000, 63.83),
+    ] {
+        let epoch = Epoch::from_gregorian_at_noon(year, 1, 1, TimeScale::TT);
+        let delta_t = epoch.delta_t_estimate().to_seconds();
+        assert!(
+            (delta_t - observed).abs() < 0.1,
+            "{year}: got {delta_t} expected {observed}"
+        );
+        assert_eq!(epoch.delta_t_uncertainty(), 0.1 * Unit::Second);
+    }
+
+    // Since 2005, ΔT is interpolated from the observed values.
+    let epoch = Epoch::from_gregorian_at_noon(2015, 1, 1, TimeScale::TT);
+    assert!((epoch.delta_t_estimate().to_seconds() - 67.6439).abs() < 1e-3);
+    let epoch = Epoch::from_gregorian_at_noon(2015, 7, 2, TimeScale::TT);
+    assert!((epoch.delta_t_estimate().to_seconds() - (67.6439 + 68.1024) / 2.0).abs() < 1e-3);
+    assert_eq!(epoch.delta_t_uncertainty(), 0.1 * Unit::Second);
+
+    // The model is continuous at the start and at the end of the observed values.
+    for (year, tol_s) in [(2005, 0.05), (2024, 1e-3)] {
+        let epoch = Epoch::from_gregorian_at_noon(year, 1, 1, TimeScale::TT);
+        let before = (epoch - 1 * Unit::Second).delta_t_estimate();
+        let after = (epoch + 1 * Unit::Second).delta_t_estimate();
+        assert!(
+            (after - before).abs() < tol_s * Unit::Second,
+            "{year}: {before} {after}"
+        );
+    }
+
+    // The uncertainty grows away from the present.
+    let uncertainty = |year| {
+        Epoch::from_gregorian_at_midnight(year, 1, 1, TimeScale::TT)
+            .delta_t_uncertainty()
+            .to_seconds()
+    };
+    assert!((uncertainty(-1000) - 0.8 * 28.2_f64.powi(2)).abs() < 1.0);
+    assert!((uncertainty(0) - 260.0).abs() < 1.0);
+    assert!((uncertainty(1650) - 12.5).abs() < 0.1);
+    assert_eq!(uncertainty(1930), 1.0);
+    assert_eq!(uncertainty(1980), 0.1);
+    assert!(uncertainty(2100) > uncertainty(2050));
+    assert!(uncertainty(2050) > uncertainty(2030));
+    assert!(uncertainty(2030) > uncertainty(2024));
+
+    // Approximate UT1 conversions
+    for year in [-500, 1200, 1900, 2000, 2100] {
+        let epoch = Epoch::from_gregorian_at_noon(year, 6, 21, TimeScale::TT);
+        let ut1_duration = epoch.to_ut1_estimate_duration();
+        assert_eq!(
+            epoch.to_tt_duration() - ut1_duration,
+            epoch.delta_t_estimate()
+        );
+        let rtn = Epoch::from_ut1_estimate_duration(ut1_duration);
+        assert_eq!(rtn.time_scale, TimeScale::TT);
+        assert!((rtn - epoch).abs() <= 1 * Unit::Microsecond, "{year}");
+    }
+}
+#[test]
 fn test_utc_str() {
     let dt_str = "2017-01-14T00:31:55 UTC";
     let dt = Epoch::from_gregorian_str(dt_str).unwrap();
@@ -1272,6 +2064,8 @@ fn test_timescale_recip() {
             TimeScale::TDB,
             TimeScale::TT,
             TimeScale::UTC,
+            TimeScale::TCG,
+            TimeScale::TCB,
         ] {
             let converted = utc_epoch.to_duration_in_time_scale(*ts);
             let from_dur = Epoch::from_duration(converted, *ts);
@@ -1355,10 +2149,11 @@ fn test_add_durations_over_leap_seconds() {
     // Before the first leap second, there is no time difference between both epochs (because only IERS announced leap seconds are accounted for by default).
     assert_eq!(pre_ls_utc - pre_ls_tai, Duration::ZERO);
     // When add 24 hours to either of the them, the UTC initialized epoch will increase the duration by 36 hours in UTC, which will cause a leap second jump.
-    // Therefore the difference between both epochs then becomes 10 seconds.
+    // Therefore the difference between both epochs then becomes the jump of TAI - UTC on 1972-01-01, from the 9.890946 seconds
+    // drifted until 1971-12-31 noon (SOFA `iauDat`: 4.2131700 + (41316.5 - 39126) × 0.002592) to 10 seconds.
     assert_eq!(
         (pre_ls_utc + 1 * Unit::Day) - (pre_ls_tai + 1 * Unit::Day),
-        10 * Unit::Second
+        109_054 * Unit::Microsecond
     );
     // Of course this works the same way the other way around
     let post_ls_utc = pre_ls_utc + Unit::Day;
@@ -1446,6 +2241,7 @@ fn test_weekday() {
             TimeScale::ET,
             TimeScale::GPST,
             TimeScale::GST,
+            TimeScale::IRNWT,
             TimeScale::TAI,
             TimeScale::TDB,
             TimeScale::TT,
@@ -1721,6 +2517,26 @@ fn test_time_of_week() {
         epoch_utc
     );
 
+    // 1W + 128H into NavIC timescale, which shares its weeks with Galileo
+    let epoch = Epoch::from_time_of_week(1, 128 * 3600 * 1_000_000_000, TimeScale::IRNWT);
+    let expected_tai =
+        TimeScale::IRNWT.reference_epoch() + Duration::from_days(7.0) + Duration::from_hours(128.0);
+    assert_eq!(epoch.to_gregorian_utc(), expected_tai.to_gregorian_utc());
+    assert_eq!(epoch.to_time_of_week(), (1, 128 * 3600 * 1_000_000_000));
+    assert_eq!(
+        epoch.to_time_scale(TimeScale::GST).to_time_of_week(),
+        (1, 128 * 3600 * 1_000_000_000)
+    );
+    assert_eq!(
+        Epoch::from_time_of_week(1, 128 * 3600 * 1_000_000_000, TimeScale::GST),
+        epoch
+    );
+
+    // <=>GPST
+    let epoch_gpst = epoch.to_time_scale(TimeScale::GPST);
+    let (week, tow) = epoch_gpst.to_time_of_week();
+    assert_eq!(Epoch::from_time_of_week(week, tow, TimeScale::GPST), epoch);
+
     // 13.5H into BeiDou timescale
     let epoch = Epoch::from_time_of_week(
         0,
@@ -1932,8 +2748,860 @@ fn test_leap_seconds_file() {
             pos += 1;
         }
     }
+
+    // The file is verified against its SHA-1 hash, and expires on 28 June 2023.
+    let provider = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    assert_eq!(
+        provider.last_update(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2016, 7, 8))
+    );
+    let expiration = Epoch::from_gregorian_utc_at_midnight(2023, 6, 28);
+    assert_eq!(provider.expiration(), Some(expiration));
+    assert!(provider.is_valid_at(expiration - 1 * Unit::Nanosecond));
+    assert!(!provider.is_valid_at(expiration));
+
+    let contents = std::fs::read_to_string("data/leap-seconds.list").unwrap();
+    let tampered = contents.replace("3692217600\t37", "3692217600\t38");
+    assert_eq!(
+        LeapSecondsFile::from_file_str(&tampered).err(),
+        Some(HifitimeError::Parse {
+            source: ParsingError::HashMismatch,
+            details: "SHA-1 hash of the leap seconds file does not match its data"
+        })
+    );
+    // The hash does not cover the comments.
+    let commented = contents.replace("# 1 Jan 2017", "# 2017-01-01");
+    assert!(LeapSecondsFile::from_file_str(&commented).is_ok());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_seconds_file_formats() {
+    use hifitime::leap_seconds::{LatestLeapSeconds, LeapSecondsFile, LeapSecondsFormat};
+    use hifitime::LineContent;
+
+    let ietf = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+
+    // The IERS Leap_Second.dat lists the same leap seconds as the IETF file.
+    let iers = LeapSecondsFile::from_path("data/Leap_Second.dat").unwrap();
+    assert_eq!(iers.clone().count(), 28);
+    assert_eq!(
+        iers.expiration(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2025, 6, 28))
+    );
+    assert_eq!(iers.last_update(), None);
+    for (pos, expected) in ietf.clone().enumerate() {
+        assert_eq!(expected, iers[pos]);
+    }
+
+    // The USNO tai-utc.dat also includes the drift of UTC from 1961 to 1972, as in SOFA.
+    let usno = LeapSecondsFile::from_path("data/tai-utc.dat").unwrap();
+    assert_eq!(usno.clone().count(), 41);
+    assert_eq!(usno.expiration(), None);
+    assert!(usno.is_valid_at(Epoch::from_gregorian_utc_at_midnight(2100, 1, 1)));
+    for (pos, expected) in LatestLeapSeconds::default().skip(1).enumerate() {
+        assert_eq!(expected, usno[pos]);
+    }
+    let epoch = Epoch::from_gregorian_utc_at_midnight(1970, 1, 1);
+    assert_eq!(
+        epoch.leap_seconds_with(false, usno),
+        epoch.leap_seconds(false)
+    );
+
+    // Explicit formats
+    let contents = "    41317.0    1  1 1972       10\n    41499.0    1  7 1972       11\n";
+    assert_eq!(
+        LeapSecondsFile::from_leap_second_dat_str(contents).unwrap()[1],
+        ietf[1]
+    );
+    let contents =
+        " 1972 JUL  1 =JD 2441499.5  TAI-UTC=  11.0       S + (MJD - 41317.) X 0.0      S\n";
+    assert_eq!(
+        LeapSecondsFile::from_tai_utc_dat_str(contents).unwrap()[0],
+        ietf[1]
+    );
+
+    // Malformed lines are reported with their number and content.
+    let line = " 1972 JUL  1 =JD 2441499.5  TAI-UTC=  eleven     S + (MJD - 41317.) X 0.0      S";
+    assert_eq!(
+        LeapSecondsFile::from_file_str(&format!("# USNO\n{line}\n")).err(),
+        Some(HifitimeError::ParseLine {
+            source: ParsingError::ValueError,
+            details: "tai-utc.dat line should have a numeric offset after `TAI-UTC=`",
+            line: 2,
+            content: LineContent::new(line),
+        })
+    );
+    let err = LeapSecondsFile::from_leap_second_dat_str("    41317.0    1  1 1972\n").unwrap_err();
+    assert_eq!(
+        err,
+        HifitimeError::ParseLine {
+            source: ParsingError::UnknownFormat,
+            details: "Leap_Second.dat should have five columns: MJD, day, month, year and TAI-UTC",
+            line: 1,
+            content: LineContent::new("    41317.0    1  1 1972"),
+        }
+    );
+    assert!(format!("{err}").contains("on line 1 `    41317.0    1  1 1972`"));
+
+    // The entries of every format must be sorted by date.
+    let not_sorted = |err: Option<HifitimeError>, line: usize| {
+        assert!(
+            matches!(
+                err,
+                Some(HifitimeError::ParseLine {
+                    source: ParsingError::ValueError,
+                    details: "leap seconds file is not sorted by date",
+                    line: l,
+                    ..
+                }) if l == line
+            ),
+            "{err:?}"
+        )
+    };
+    not_sorted(
+        LeapSecondsFile::from_leap_second_dat_str(
+            "    41499.0    1  7 1972       11\n    41317.0    1  1 1972       10\n",
+        )
+        .err(),
+        2,
+    );
+    not_sorted(
+        LeapSecondsFile::from_tai_utc_dat_str(
+            " 1972 JUL  1 =JD 2441499.5  TAI-UTC=  11.0       S + (MJD - 41317.) X 0.0      S\n \
+             1972 JUL  1 =JD 2441499.5  TAI-UTC=  11.0       S + (MJD - 41317.) X 0.0      S\n",
+        )
+        .err(),
+        2,
+    );
+    not_sorted(
+        LeapSecondsFile::from_leap_seconds_list_str("3692217600\t37\n2272060800\t10\n").err(),
+        2,
+    );
+
+    // The format is detected from the markers of each format, or from the first data line without any.
+    for (path, format) in [
+        ("data/leap-seconds.list", LeapSecondsFormat::LeapSecondsList),
+        ("data/tai-utc.dat", LeapSecondsFormat::TaiUtcDat),
+        ("data/Leap_Second.dat", LeapSecondsFormat::LeapSecondDat),
+    ] {
+        let contents = std::fs::read_to_string(path).unwrap();
+        assert_eq!(LeapSecondsFormat::detect(&contents), Some(format));
+        assert_eq!(
+            LeapSecondsFile::from_path_with_format(path, format)
+                .unwrap()
+                .collect::<Vec<_>>(),
+            LeapSecondsFile::from_path(path)
+                .unwrap()
+                .collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(
+        LeapSecondsFormat::detect("2272060800\t10\t# 1 Jan 1972\n"),
+        Some(LeapSecondsFormat::LeapSecondsList)
+    );
+    assert_eq!(
+        LeapSecondsFormat::detect("    41317.0    1  1 1972       10\n"),
+        Some(LeapSecondsFormat::LeapSecondDat)
+    );
+    // A Leap_Second.dat line with a missing column is not mistaken for another format.
+    assert_eq!(
+        LeapSecondsFormat::detect("    41317.0    1  1 1972\n"),
+        Some(LeapSecondsFormat::LeapSecondDat)
+    );
+    assert_eq!(LeapSecondsFormat::detect("1972-01-01 10\n"), None);
+    assert_eq!(
+        LeapSecondsFile::from_file_str("1972-01-01 10\n").err(),
+        Some(HifitimeError::Parse {
+            source: ParsingError::UnknownFormat,
+            details: "leap seconds file format could not be detected from its header or data"
+        })
+    );
+    assert!(LeapSecondsFile::from_file_str("# Only comments\n").is_err());
+}
+
+#[test]
+fn test_leap_second_lookup() {
+    use hifitime::leap_seconds::{
+        LatestLeapSeconds, LeapSecond, LeapSecondSlice, LeapSecondTable, LookupCache,
+    };
+
+    /// Linear scan of the leap seconds from the latest, which is how they were looked up before the binary search.
+    fn linear_scan<I: DoubleEndedIterator<Item = LeapSecond>>(
+        epoch: Epoch,
+        iers_only: bool,
+        leap_seconds: I,
+    ) -> Option<f64> {
+        let mut leap_seconds = leap_seconds
+            .rev()
+            .filter(|leap_second| !iers_only || leap_second.announced_by_iers)
+            .peekable();
+        while let Some(leap_second) = leap_seconds.next() {
+            let midnight = leap_second.timestamp_tai_s * Unit::Second;
+            if epoch.time_scale == TimeScale::UTC {
+                if epoch.duration >= midnight {
+                    return Some(
+                        leap_second
+                            .delta_at_on_mjd_utc(epoch.duration.to_unit(Unit::Day) + MJD_J1900),
+                    );
+                }
+            } else {
+                let start = if leap_seconds.peek().is_some() {
+                    midnight
+                        + leap_second.delta_at_on_mjd_utc(
+                            leap_second.timestamp_tai_s / SECONDS_PER_DAY + MJD_J1900,
+                        ) * Unit::Second
+                } else {
+                    midnight
+                };
+                if epoch.to_tai_duration() >= start {
+                    return Some(leap_second.delta_at_on_mjd_tai(epoch.to_mjd_tai_days()));
+                }
+            }
+        }
+        None
+    }
+
+    let latest = LatestLeapSeconds::default();
+    assert_eq!(LeapSecondSlice::latest().as_slice(), latest.as_slice());
+    assert_eq!(latest.clone().count(), latest.as_slice().len());
+
+    // Around each leap second, within and after it, and from one to the other such that the last hit is both reused and missed.
+    let mut epochs = vec![Epoch::from_gregorian_tai_at_midnight(1950, 1, 1)];
+    for leap_second in latest.as_slice() {
+        let midnight = leap_second.timestamp_tai_s * Unit::Second;
+        for offset_s in [-86_400, -1, 0, 1, 5, 10, 11, 12, 37, 38, 86_400] {
+            let tai = Epoch::from_tai_duration(midnight + offset_s * Unit::Second);
+            for ts in [TimeScale::TAI, TimeScale::UTC, TimeScale::TT] {
+                epochs.push(tai.to_time_scale(ts));
+            }
+            // The UTC reading of the midnight offset
+            epochs.push(Epoch::from_utc_duration(midnight + offset_s * Unit::Second));
+        }
+    }
+    epochs.push(Epoch::from_gregorian_tai_at_midnight(2100, 1, 1));
+
+    for iers_only in [true, false] {
+        for epoch in &epochs {
+            let expected = linear_scan(*epoch, iers_only, LatestLeapSeconds::default());
+            assert_eq!(
+                epoch.leap_seconds_with(iers_only, LatestLeapSeconds::default()),
+                expected,
+                "{epoch:?} iers_only={iers_only}"
+            );
+            assert_eq!(
+                epoch.leap_seconds_with(iers_only, &latest),
+                expected,
+                "{epoch:?} iers_only={iers_only}"
+            );
+            assert_eq!(epoch.leap_seconds(iers_only), expected);
+        }
+        // In reverse order as well, such that every lookup misses the last hit.
+        for epoch in epochs.iter().rev().step_by(3) {
+            assert_eq!(
+                epoch.leap_seconds_with(iers_only, LeapSecondSlice::latest()),
+                linear_scan(*epoch, iers_only, LatestLeapSeconds::default()),
+            );
+        }
+    }
+
+    // An empty provider or one with a single leap second.
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2020, 1, 1);
+    assert_eq!(
+        epoch.leap_seconds_with(true, LeapSecondSlice::new(&[]).unwrap()),
+        None
+    );
+    let single = [LeapSecond::new(3_692_217_600.0, 37.0, true)];
+    assert_eq!(
+        epoch.leap_seconds_with(true, LeapSecondSlice::new(&single).unwrap()),
+        Some(37.0)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_tai_hms(2017, 1, 1, 0, 0, 1)
+            .leap_seconds_with(true, LeapSecondSlice::new(&single).unwrap()),
+        Some(37.0)
+    );
+    assert_eq!(
+        Epoch::from_gregorian_tai_hms(2016, 12, 31, 23, 59, 59)
+            .leap_seconds_with(true, LeapSecondSlice::new(&single).unwrap()),
+        None
+    );
+
+    // Leap seconds which are not sorted by date are rejected.
+    let unsorted = [single[0], LeapSecond::new(2_272_060_800.0, 10.0, true)];
+    assert!(LeapSecondSlice::new(&unsorted).is_err());
+    assert!(LeapSecondSlice::new(&[single[0], single[0]]).is_err());
+
+    // Each provider has its own lookup cache, so alternating between providers gives the same results.
+    let cache = LookupCache::new();
+    let cached = LeapSecondSlice::with_cache(&single, &cache).unwrap();
+    for epoch in [
+        Epoch::from_gregorian_utc_at_midnight(1980, 1, 1),
+        Epoch::from_gregorian_utc_at_midnight(2020, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(1980, 1, 1),
+    ] {
+        for _ in 0..2 {
+            assert_eq!(
+                epoch.leap_seconds_with(true, cached),
+                linear_scan(epoch, true, single.iter().copied())
+            );
+            assert_eq!(
+                epoch.leap_seconds_with(true, LeapSecondSlice::latest()),
+                linear_scan(epoch, true, LatestLeapSeconds::default())
+            );
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+#[test]
+#[allow(deprecated)]
+fn test_leap_second_iter_adapter() {
+    use core::ops::Index;
+    use hifitime::leap_seconds::{
+        LatestLeapSeconds, LeapSecond, LeapSecondIterAdapter, LeapSecondProvider,
+    };
+
+    /// A provider of hifitime 3, which only iterates over its leap seconds.
+    struct IterProvider {
+        data: Vec<LeapSecond>,
+        iter_pos: usize,
+    }
+
+    impl Iterator for IterProvider {
+        type Item = LeapSecond;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter_pos += 1;
+            self.data.get(self.iter_pos - 1).copied()
+        }
+    }
+
+    impl DoubleEndedIterator for IterProvider {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            self.data.pop()
+        }
+    }
+
+    impl Index<usize> for IterProvider {
+        type Output = LeapSecond;
+
+        fn index(&self, index: usize) -> &Self::Output {
+            self.data.index(index)
+        }
+    }
+
+    impl LeapSecondProvider for IterProvider {}
+
+    let provider = IterProvider {
+        data: LatestLeapSeconds::default().collect(),
+        iter_pos: 0,
+    };
+    let adapter = LeapSecondIterAdapter::new(provider).unwrap();
+    for epoch in [
+        Epoch::from_gregorian_utc_at_midnight(1972, 1, 1),
+        Epoch::from_gregorian_utc_at_midnight(2020, 1, 1),
+        Epoch::from_gregorian_tai_at_midnight(2017, 1, 1),
+    ] {
+        assert_eq!(
+            epoch.leap_seconds_with(false, &adapter),
+            epoch.leap_seconds(false)
+        );
+    }
+
+    // The built-in providers still implement the deprecated trait.
+    assert!(LeapSecondIterAdapter::new(LatestLeapSeconds::default()).is_ok());
+
+    let unsorted = IterProvider {
+        data: LatestLeapSeconds::default().rev().collect(),
+        iter_pos: 0,
+    };
+    assert!(LeapSecondIterAdapter::new(unsorted).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_second_kernel() {
+    use hifitime::leap_seconds::{LeapSecondKernel, LeapSecondsFile};
+    use hifitime::EtModel;
+
+    let provider = LeapSecondKernel::from_path("naif0012.txt").unwrap();
+    let iers = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+
+    // The LSK lists the same leap seconds as the IERS.
+    assert_eq!(provider.clone().count(), 28);
+    for (pos, expected) in iers.enumerate() {
+        assert_eq!(expected, provider[pos]);
+    }
+
+    // The ET model of naif0012 is the one used by the ET time scale.
+    let model = provider.et_model();
+    assert_eq!(model, EtModel::default());
+    assert_eq!(model.delta_t_a, 32.184);
+    assert_eq!(model.m1, 1.99096871e-7);
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 11, 3, 12, 30, 0);
+    assert_eq!(
+        epoch.to_et_duration_with_model(model),
+        epoch.to_et_duration()
+    );
+    assert!(
+        (Epoch::from_et_duration_with_model(epoch.to_et_duration(), model) - epoch).abs()
+            < 10 * Unit::Nanosecond
+    );
+
+    // A kernel with different constants changes the ET conversions.
+    let contents = r"
+KPL/LSK
+
+\begindata
+
+DELTET/DELTA_T_A       =   32.184
+DELTET/K               =    0.0
+DELTET/EB              =    1.671D-2
+DELTET/M               = (  6.239996D0   1.99096871D-7 )
+DELTET/DELTA_AT        = ( 10,   @1972-JAN-1
+                           37,   @2017-JAN-1 )
+
+\begintext
+";
+    let provider = LeapSecondKernel::from_kernel_str(contents).unwrap();
+    assert_eq!(provider.clone().count(), 2);
+    assert_eq!(epoch.leap_seconds_with(true, provider.clone()), Some(37.0));
+    // Without the periodic term, ET is TT.
+    let model = provider.et_model();
+    assert!(
+        (epoch.to_et_duration_with_model(model) - epoch.to_tt_since_j2k()).abs()
+            <= Unit::Nanosecond
+    );
+    assert!(
+        (Epoch::from_et_duration_with_model(epoch.to_tt_since_j2k(), model) - epoch).abs()
+            <= Unit::Nanosecond
+    );
+
+    assert!(LeapSecondKernel::from_kernel_str("\\begindata\nDELTET/K = 1.657D-3\n").is_err());
+    // The leap seconds must be sorted by date.
+    assert!(LeapSecondKernel::from_kernel_str(&contents.replace(
+        "( 10,   @1972-JAN-1\n                           37,   @2017-JAN-1 )",
+        "( 37,   @2017-JAN-1\n                           10,   @1972-JAN-1 )"
+    ))
+    .is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_leap_seconds_export() {
+    use hifitime::leap_seconds::{
+        LatestLeapSeconds, LeapSecond, LeapSecondKernel, LeapSecondTable, LeapSecondsFile,
+    };
+    use hifitime::EtModel;
+
+    let ietf = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    let latest = LatestLeapSeconds::default();
+    let iers_only: Vec<LeapSecond> = latest
+        .clone()
+        .filter(|leap_second| leap_second.announced_by_iers)
+        .collect();
+
+    // IETF leap-seconds.list: the hash is recomputed and verified when parsing the export.
+    let list = ietf
+        .clone()
+        .to_leap_seconds_list(ietf.last_update().unwrap(), ietf.expiration().unwrap());
+    assert!(list.contains("#$\t3676924800\n"));
+    assert!(list.contains("#@\t3896899200\n"));
+    assert!(list.contains("2272060800\t10\t# 1 Jan 1972\n"));
+    assert!(list.contains("3692217600\t37\t# 1 Jan 2017\n"));
+    // Same hash as the IETF file, written in the same way.
+    let original = std::fs::read_to_string("data/leap-seconds.list").unwrap();
+    let hash_line = original
+        .lines()
+        .find(|line| line.starts_with("#h"))
+        .unwrap();
+    assert!(list.contains(&hash_line.replace(" \t", "\t").replace("#h ", "#h\t")));
+    let parsed = LeapSecondsFile::from_file_str(&list).unwrap();
+    assert_eq!(parsed.last_update(), ietf.last_update());
+    assert_eq!(parsed.expiration(), ietf.expiration());
+    assert_eq!(parsed.clone().collect::<Vec<_>>(), iers_only);
+    assert_eq!(
+        LeapSecondsFile::from_file_str(
+            &latest
+                .clone()
+                .to_leap_seconds_list(ietf.last_update().unwrap(), ietf.expiration().unwrap(),)
+        )
+        .unwrap()
+        .collect::<Vec<_>>(),
+        iers_only
+    );
+
+    // NAIF LSK
+    let lsk = latest.clone().to_naif_lsk(EtModel::default());
+    assert!(lsk.contains("DELTET/DELTA_AT        = ( 10,   @1972-JAN-1\n"));
+    assert!(lsk.contains(" 37,   @2017-JAN-1\n"));
+    let kernel = LeapSecondKernel::from_kernel_str(&lsk).unwrap();
+    assert_eq!(kernel.et_model(), EtModel::default());
+    assert_eq!(kernel.collect::<Vec<_>>(), iers_only);
+    let model = EtModel::new(32.184, 0.0, 1.671e-2, 6.239996, 1.99096871e-7);
+    assert_eq!(
+        LeapSecondKernel::from_kernel_str(&ietf.clone().to_naif_lsk(model))
+            .unwrap()
+            .et_model(),
+        model
+    );
+
+    // USNO tai-utc.dat, including the drift of UTC before 1972
+    let tai_utc = latest.clone().to_tai_utc_dat();
+    assert!(tai_utc.contains(
+        " 1961 JAN  1 =JD 2437300.5  TAI-UTC=    1.4228180 S + (MJD - 37300.) X 0.001296 S\n"
+    ));
+    assert!(tai_utc.contains(
+        " 2017 JAN  1 =JD 2457754.5  TAI-UTC=   37.0000000 S + (MJD - 41317.) X 0.0 S\n"
+    ));
+    let parsed = LeapSecondsFile::from_file_str(&tai_utc).unwrap();
+    assert_eq!(
+        parsed.collect::<Vec<_>>(),
+        latest.clone().collect::<Vec<_>>()
+    );
+    let usno = LeapSecondsFile::from_path("data/tai-utc.dat").unwrap();
+    assert_eq!(
+        LeapSecondsFile::from_file_str(&usno.clone().to_tai_utc_dat())
+            .unwrap()
+            .collect::<Vec<_>>(),
+        usno.collect::<Vec<_>>()
+    );
+
+    let leap_second = |timestamp_tai_s,
+                       delta_at,
+                       announced_by_iers,
+                       drift_ref_mjd,
+                       drift_s_per_day| {
+        if drift_s_per_day == 0.0 {
+            LeapSecond::new(timestamp_tai_s, delta_at, announced_by_iers)
+        } else {
+            LeapSecond::new_with_drift(timestamp_tai_s, delta_at, drift_ref_mjd, drift_s_per_day)
+        }
+    };
+
+    // CSV
+    let csv = latest.clone().to_csv();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("timestamp_tai_s,delta_at,announced_by_iers,drift_ref_mjd,drift_s_per_day")
+    );
+    let from_csv: Vec<LeapSecond> = lines
+        .map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            leap_second(
+                fields[0].parse().unwrap(),
+                fields[1].parse().unwrap(),
+                fields[2].parse().unwrap(),
+                fields[3].parse().unwrap(),
+                fields[4].parse().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(from_csv, latest.clone().collect::<Vec<_>>());
+
+    // JSON
+    let json: serde_json::Value = serde_json::from_str(&latest.clone().to_json()).unwrap();
+    let from_json: Vec<LeapSecond> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            leap_second(
+                entry["timestamp_tai_s"].as_f64().unwrap(),
+                entry["delta_at"].as_f64().unwrap(),
+                entry["announced_by_iers"].as_bool().unwrap(),
+                entry["drift_ref_mjd"].as_f64().unwrap(),
+                entry["drift_s_per_day"].as_f64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(from_json, latest.collect::<Vec<_>>());
+}
+
+hifitime::leap_seconds_list!(
+    /// The IETF leap seconds list of the fixtures, parsed at compile time.
+    static IETF_LEAP_SECONDS = include_str!("../data/leap-seconds.list")
+);
+
+#[test]
+fn test_leap_seconds_list() {
+    use hifitime::leap_seconds::{
+        leap_seconds_list_len, LatestLeapSeconds, LeapSecondTable, LeapSecondsList,
+    };
+
+    assert_eq!(IETF_LEAP_SECONDS.as_slice().len(), 28);
+    assert_eq!(
+        leap_seconds_list_len(include_str!("../data/leap-seconds.list")),
+        28
+    );
+    assert_eq!(IETF_LEAP_SECONDS[27].delta_at, 37.0);
+    // The IETF leap seconds are the ones announced by the IERS of the built-in ones.
+    let latest = LatestLeapSeconds::default();
+    let iers: Vec<_> = latest
+        .as_slice()
+        .iter()
+        .filter(|leap_second| leap_second.announced_by_iers)
+        .collect();
+    assert_eq!(iers.len(), 28);
+    for (leap_second, expected) in IETF_LEAP_SECONDS.as_slice().iter().zip(iers) {
+        assert_eq!(leap_second, expected);
+    }
+    assert_eq!(
+        IETF_LEAP_SECONDS.last_update(),
+        Some(Epoch::from_utc_seconds(3_676_924_800.0))
+    );
+    assert_eq!(
+        IETF_LEAP_SECONDS.expiration(),
+        Some(Epoch::from_gregorian_utc_at_midnight(2023, 6, 28))
+    );
+    assert!(IETF_LEAP_SECONDS.is_valid_at(Epoch::from_gregorian_utc_at_midnight(2023, 1, 1)));
+    assert!(!IETF_LEAP_SECONDS.is_valid_at(Epoch::from_gregorian_utc_at_midnight(2024, 1, 1)));
+
+    #[cfg(feature = "std")]
+    {
+        use hifitime::leap_seconds::LeapSecondsFile;
+        let file = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+        assert_eq!(IETF_LEAP_SECONDS.as_slice(), file.as_slice());
+        assert_eq!(IETF_LEAP_SECONDS.last_update(), file.last_update());
+        assert_eq!(IETF_LEAP_SECONDS.expiration(), file.expiration());
+    }
+
+    let epoch = Epoch::from_gregorian_tai_at_midnight(2020, 1, 1);
+    assert_eq!(
+        epoch.to_time_scale_with_leap_seconds(TimeScale::UTC, &IETF_LEAP_SECONDS),
+        epoch.to_time_scale(TimeScale::UTC)
+    );
+    assert_eq!(
+        epoch.leap_seconds_with(true, &IETF_LEAP_SECONDS),
+        Some(37.0)
+    );
+
+    // Evaluated in a const context, and with Windows line endings and without hash
+    const CRLF: LeapSecondsList<2> = match LeapSecondsList::from_leap_seconds_list_str(
+        "# Comment\r\n#$ 3676924800\r\n\r\n2272060800 10 # 1 Jan 1972\r\n2287785600\t11\r\n",
+    ) {
+        Ok(list) => list,
+        Err(_) => panic!("invalid leap seconds list"),
+    };
+    assert_eq!(CRLF[1].timestamp_tai_s, 2_287_785_600.0);
+    assert_eq!(CRLF[1].delta_at, 11.0);
+    assert_eq!(CRLF.expiration(), None);
+    assert!(CRLF.is_valid_at(epoch));
+
+    // Validation errors, which fail the build of the leap_seconds_list! macro.
+    let details = |result: Result<LeapSecondsList<2>, HifitimeError>| match result {
+        Err(HifitimeError::Parse { details, .. }) => details,
+        _ => panic!("expected a parsing error"),
+    };
+    assert_eq!(
+        details(LeapSecondsList::from_leap_seconds_list_str(
+            "2287785600\t11\n2272060800\t10\n"
+        )),
+        "leap seconds file is not sorted by date"
+    );
+    assert_eq!(
+        details(LeapSecondsList::from_leap_seconds_list_str(
+            "2272060800\t10\n2272060800\t11\n"
+        )),
+        "leap seconds file is not sorted by date"
+    );
+    for (contents, expected) in [
+        (
+            "2272060800\t10\nJuly 1972\t11\n",
+            "first column value is not numeric",
+        ),
+        (
+            "2272060800\t10\n2287785600\televen\n",
+            "second column value is not numeric",
+        ),
+        (
+            "2272060800\t10\n2287785600\n",
+            "leap seconds file should have two columns exactly",
+        ),
+        (
+            "2272060800\t10\n2287785600\t11\t12\n",
+            "leap seconds file should have two columns exactly",
+        ),
+        (
+            "2272060800\t10\n2287785600\t11#\n",
+            "leap seconds file should have two columns exactly",
+        ),
+        (
+            "#@ soon\n2272060800\t10\n2287785600\t11\n",
+            "leap seconds file update or expiration time is not numeric",
+        ),
+        (
+            "#h 0 1 2 3\n2272060800\t10\n2287785600\t11\n",
+            "leap seconds file hash should have five hexadecimal words",
+        ),
+        (
+            "#h 0 1 2 3 4 5\n2272060800\t10\n2287785600\t11\n",
+            "leap seconds file hash should have five words",
+        ),
+        (
+            "#h 0 1 2 3 4\n2272060800\t10\n2287785600\t11\n",
+            "SHA-1 hash of the leap seconds file does not match its data",
+        ),
+        (
+            "2272060800\t10\n2287785600\t11\n2303683200\t12\n",
+            "leap seconds file has more leap seconds than the list",
+        ),
+        (
+            "2272060800\t10\n",
+            "leap seconds file has fewer leap seconds than the list",
+        ),
+        ("# Nothing\n\n", "leap seconds file has no leap seconds"),
+    ] {
+        assert_eq!(
+            details(LeapSecondsList::from_leap_seconds_list_str(contents)),
+            expected,
+            "{contents:?}"
+        );
+    }
+
+    // Changing a value of the IETF file breaks its hash.
+    let tampered =
+        include_str!("../data/leap-seconds.list").replace("3692217600\t37", "3692217600\t38");
+    assert_eq!(
+        LeapSecondsList::<28>::from_leap_seconds_list_str(&tampered),
+        Err(HifitimeError::Parse {
+            source: ParsingError::HashMismatch,
+            details: "SHA-1 hash of the leap seconds file does not match its data",
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_tt_bipm_file() {
+    use hifitime::{TtBipmCorrection, TtBipmFile};
+
+    let provider = TtBipmFile::from_path("data/ttbipm-fixture.txt").unwrap();
+    assert_eq!(provider.realization(), Some("TT(BIPM23)"));
+    assert_eq!(provider.len(), 13);
+    assert_eq!(provider[0], TtBipmCorrection::new(59_580.0, 27.6661));
+    assert_eq!(provider[12], TtBipmCorrection::new(59_640.0, 27.6691));
+    assert_eq!(provider.as_slice().len(), 13);
+    assert_eq!(
+        provider.as_slice().last(),
+        Some(&TtBipmCorrection::new(59_640.0, 27.6691))
+    );
+    // Iterating does not consume the provider, and works from either end.
+    assert_eq!(provider.iter().count(), 13);
+    assert_eq!(
+        provider.iter().next_back(),
+        Some(&TtBipmCorrection::new(59_640.0, 27.6691))
+    );
+    assert!((&provider)
+        .into_iter()
+        .zip(provider.iter().skip(1))
+        .all(|(prev, next)| prev.mjd_tai < next.mjd_tai));
+    assert_eq!(
+        provider.clone().into_iter().rev().last(),
+        Some(TtBipmCorrection::new(59_580.0, 27.6661))
+    );
+
+    // On a tabulated date
+    let epoch = Epoch::from_mjd_tai(59_585.0);
+    assert_eq!(
+        epoch.tt_bipm_correction(&provider),
+        Some(27.6663 * Unit::Microsecond)
+    );
+    let tt_bipm = epoch.to_tt_bipm(&provider).unwrap();
+    assert_eq!(
+        tt_bipm - epoch.to_tt_duration(),
+        27.6663 * Unit::Microsecond
+    );
+    assert_eq!(
+        format!("{}", Epoch::from_duration(tt_bipm, TimeScale::TT)),
+        "2022-01-06T00:00:32.184027666 TT"
+    );
+
+    // Linear interpolation between two dates
+    let epoch = Epoch::from_mjd_tai(59_587.5);
+    assert_eq!(
+        epoch.tt_bipm_correction(&provider),
+        Some(27.66645 * Unit::Microsecond)
+    );
+    let rtn = Epoch::from_tt_bipm(epoch.to_tt_bipm(&provider).unwrap(), &provider).unwrap();
+    assert_eq!(rtn, epoch);
+
+    // Outside of the file
+    assert!(Epoch::from_mjd_tai(59_579.9)
+        .to_tt_bipm(&provider)
+        .is_none());
+    assert!(Epoch::from_mjd_tai(59_640.0)
+        .to_tt_bipm(&provider)
+        .is_some());
+    assert!(Epoch::from_mjd_tai(59_640.1)
+        .to_tt_bipm(&provider)
+        .is_none());
+
+    // Invalid files
+    assert!(TtBipmFile::from_file_str("no data here").is_err());
+    assert!(TtBipmFile::from_file_str("59580 27.6661\n59575 27.6662").is_err());
+    assert!(TtBipmFile::from_file_str("59580 twenty").is_err());
+    assert!(TtBipmFile::from_path("data/does-not-exist").is_err());
+}
+
+#[test]
+fn test_circular_t() {
+    use hifitime::CircularT;
+
+    let provider = CircularT::from_path("data/circular-t-fixture.txt").unwrap();
+    assert_eq!(provider.mjd_utc().len(), 7);
+    assert_eq!(provider.mjd_utc()[0], 60_305.0);
+    // The values of section 2 are not UTC(k) realizations.
+    assert_eq!(provider.realizations().len(), 6);
+    assert!(provider.realization("XYZ").is_none());
+    let su = provider.realization("SU").unwrap();
+    assert_eq!(su.offsets_ns[0], Some(2.4));
+    assert_eq!(su.offsets_ns[1], None);
+    assert_eq!(
+        provider.realization("USNO").unwrap().offsets_ns[6],
+        Some(-0.2)
+    );
+
+    // On a tabulated date
+    let epoch = Epoch::from_gregorian_utc_at_midnight(2024, 1, 6);
+    assert_eq!(epoch.to_mjd_utc_days(), 60_315.0);
+    assert_eq!(
+        epoch.utc_k_offset("ONRJ", &provider),
+        Some(16 * Unit::Nanosecond)
+    );
+
+    // Linear interpolation between two dates
+    let epoch = Epoch::from_gregorian_utc_at_noon(2024, 1, 3);
+    assert_eq!(
+        epoch.utc_k_offset("ONRJ", &provider),
+        Some(15 * Unit::Nanosecond)
+    );
+    let utc_k = epoch.to_utc_k("ONRJ", &provider).unwrap();
+    assert_eq!(utc_k.time_scale, TimeScale::UTC);
+    assert_eq!(format!("{utc_k}"), "2024-01-03T11:59:59.999999985 UTC");
+    assert_eq!(
+        Epoch::from_utc_k_duration(utc_k.to_utc_duration(), "ONRJ", &provider),
+        Some(epoch)
+    );
+    // Missing values are skipped.
+    assert!(epoch.utc_k_offset("SU", &provider).is_some());
+
+    // Outside of the Circular, or for an unknown laboratory
+    assert!(Epoch::from_gregorian_utc_at_midnight(2023, 12, 26)
+        .utc_k_offset("USNO", &provider)
+        .is_none());
+    assert!(Epoch::from_gregorian_utc_at_noon(2024, 1, 26)
+        .utc_k_offset("USNO", &provider)
+        .is_none());
+    assert!(epoch.utc_k_offset("NPL", &provider).is_none());
+
+    // Invalid files
+    assert!(CircularT::from_file_str("").is_err());
+    assert!(CircularT::from_file_str("MJD 60305 60310\nUSNO (Washington DC) -0.1\n").is_err());
+    assert!(CircularT::from_file_str("MJD 60305 60310\nUSNO (Washington DC) -0.1 abc\n").is_err());
+}
 #[test]
 fn regression_test_gh_204() {
     use core::str::FromStr;
@@ -2136,4 +3804,10 @@ fn regression_test_gh_317() {
             .unwrap(),
         nanos
     );
+    assert_eq!(
+        Epoch::from_irnwt_nanoseconds(nanos)
+            .to_irnwt_nanoseconds()
+            .unwrap(),
+        nanos
+    );
 }
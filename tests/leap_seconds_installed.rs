@@ -0,0 +1,93 @@
+// The installed leap seconds are global to the process, so this test has its own binary.
+#[cfg(feature = "std")]
+#[test]
+fn test_installed_leap_seconds() {
+    use hifitime::leap_seconds::{
+        install_provider, uninstall_provider, LatestLeapSeconds, LeapSecond, LeapSecondTable,
+        LeapSecondsFile,
+    };
+    use hifitime::{Epoch, TimeScale, Unit};
+
+    /// The built-in leap seconds and a fictitious one on 3000-01-01, far after any other test.
+    #[derive(Clone)]
+    struct FutureLeapSeconds {
+        data: Vec<LeapSecond>,
+    }
+
+    impl LeapSecondTable for FutureLeapSeconds {
+        fn as_slice(&self) -> &[LeapSecond] {
+            &self.data
+        }
+    }
+
+    let leap_second = Epoch::from_gregorian_tai_at_midnight(3000, 1, 1);
+    let mut data: Vec<LeapSecond> = LatestLeapSeconds::default().collect();
+    data.push(LeapSecond::new(leap_second.to_tai_seconds(), 38.0, true));
+    let provider = FutureLeapSeconds { data };
+
+    let epoch = Epoch::from_gregorian_tai_at_midnight(3000, 1, 2);
+
+    // Explicit conversion context
+    let utc = epoch.to_time_scale_with_leap_seconds(TimeScale::UTC, &provider);
+    assert_eq!(format!("{utc}"), "3000-01-01T23:59:22 UTC");
+    // The UTC epoch does not keep the provider: converting it again uses the built-in leap seconds.
+    assert_eq!(utc.to_time_scale(TimeScale::TAI), epoch - 1 * Unit::Second);
+    assert_eq!(utc.leap_seconds_with(true, &provider), Some(38.0));
+    assert_eq!(
+        utc.to_time_scale_with_leap_seconds(TimeScale::TAI, &provider),
+        epoch
+    );
+    assert_eq!(
+        epoch.to_time_scale_with_leap_seconds(TimeScale::UTC, &LatestLeapSeconds::default()),
+        epoch.to_time_scale(TimeScale::UTC)
+    );
+    // Before the fictitious leap second, the IETF file and the provider match the built-in leap seconds.
+    let ietf = LeapSecondsFile::from_path("data/leap-seconds.list").unwrap();
+    let before = Epoch::from_gregorian_tai_at_midnight(2024, 1, 1);
+    for ts in [TimeScale::UTC, TimeScale::GLONASST, TimeScale::UTCSLS] {
+        assert_eq!(
+            before.to_time_scale_with_leap_seconds(ts, &ietf).duration,
+            before.to_time_scale(ts).duration
+        );
+        assert_eq!(
+            before
+                .to_time_scale_with_leap_seconds(ts, &provider)
+                .duration,
+            before.to_time_scale(ts).duration
+        );
+    }
+
+    // Process wide default
+    let builtin_utc = epoch.to_time_scale(TimeScale::UTC);
+    assert_eq!(format!("{builtin_utc}"), "3000-01-01T23:59:23 UTC");
+    let builtin_glonasst = epoch.to_time_scale(TimeScale::GLONASST);
+
+    // Leap seconds which are not sorted by date are not installed.
+    let mut unsorted = provider.clone();
+    unsorted.data.reverse();
+    assert!(install_provider(unsorted).is_err());
+    assert_eq!(epoch.to_time_scale(TimeScale::UTC), builtin_utc);
+
+    install_provider(provider).unwrap();
+    assert_eq!(epoch.to_time_scale(TimeScale::UTC), utc);
+    assert_eq!(
+        format!("{}", epoch.to_time_scale(TimeScale::UTC)),
+        "3000-01-01T23:59:22 UTC"
+    );
+    assert_eq!(epoch.leap_seconds(true), Some(38.0));
+    assert_eq!(epoch.to_gregorian_utc(), (3000, 1, 1, 23, 59, 22, 0));
+    assert_eq!(
+        builtin_glonasst.duration - epoch.to_time_scale(TimeScale::GLONASST).duration,
+        1 * Unit::Second
+    );
+    assert_eq!(
+        Epoch::from_gregorian_utc_at_midnight(3000, 1, 1).to_tai_duration()
+            - Epoch::from_gregorian_utc_at_midnight(2999, 12, 31).to_tai_duration(),
+        1 * Unit::Day + 1 * Unit::Second
+    );
+
+    assert_eq!(uninstall_provider().map(|data| data.len()), Some(43));
+    assert_eq!(uninstall_provider(), None);
+    assert_eq!(epoch.to_time_scale(TimeScale::UTC), builtin_utc);
+    assert_eq!(epoch.leap_seconds(true), Some(37.0));
+}
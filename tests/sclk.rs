@@ -0,0 +1,122 @@
+extern crate hifitime;
+
+use hifitime::{Epoch, HifitimeError, Sclk, TimeScale, Unit};
+
+fn fixture() -> Sclk {
+    Sclk::from_path("data/sclk-fixture.tsc", -999).unwrap()
+}
+
+#[test]
+fn sclk_kernel() {
+    let sclk = fixture();
+    assert_eq!(sclk.naif_id, -999);
+    assert_eq!(sclk.time_scale, TimeScale::TT);
+    assert_eq!(sclk.moduli, vec![4_294_967_296, 256]);
+    assert_eq!(sclk.offsets, vec![0, 0]);
+    assert_eq!(sclk.output_delimiter, '.');
+    assert_eq!(
+        sclk.partitions,
+        vec![(0.0, 25_600_000.0), (25_856_000.0, 1_099_511_627_775.0)]
+    );
+    assert_eq!(sclk.coefficients.len(), 3);
+    assert_eq!(
+        sclk.coefficients[2],
+        [25_600_000.0, 757_440_000.0, 0.999_999]
+    );
+
+    // Another spacecraft is not in this kernel
+    assert!(Sclk::from_path("data/sclk-fixture.tsc", -82).is_err());
+    assert!(Sclk::from_path("data/does-not-exist.tsc", -999).is_err());
+
+    // Kernels whose ticks per count or fields overflow 64 bits are rejected.
+    let contents = std::fs::read_to_string("data/sclk-fixture.tsc").unwrap();
+    for (moduli, offsets) in [
+        ("( 256 4294967296 4294967296 )", "( 0 0 0 )"),
+        ("( 4294967296 256 )", "( 0 18446744073709551615 )"),
+    ] {
+        let kernel = contents
+            .replace("SCLK01_N_FIELDS_999      = ( 2 )", "")
+            .replace("( 4294967296 256 )", moduli)
+            .replace("( 0 0 )", offsets);
+        assert!(
+            matches!(
+                Sclk::from_kernel_str(&kernel, -999),
+                Err(HifitimeError::Parse { details, .. }) if details.contains("overflow")
+            ),
+            "{moduli}"
+        );
+    }
+}
+
+#[test]
+fn sclk_encode_decode() {
+    let sclk = fixture();
+
+    assert_eq!(sclk.encode("1/0000025000.128").unwrap(), 6_400_128.0);
+    assert_eq!(sclk.decode(6_400_128.0).unwrap(), "1/0000025000.128");
+    // Any delimiter may be used, and missing trailing fields are zero.
+    assert_eq!(sclk.encode("1/25000:128").unwrap(), 6_400_128.0);
+    assert_eq!(sclk.encode("1/25000").unwrap(), 6_400_000.0);
+
+    // Second partition, where the clock was reset to 101000 seconds.
+    assert_eq!(sclk.encode("2/0000101000.000").unwrap(), 25_600_000.0);
+    assert_eq!(sclk.encode("2/101100.64").unwrap(), 25_625_664.0);
+    assert_eq!(sclk.decode(25_625_664.0).unwrap(), "2/0000101100.064");
+    // The end of the first partition is decoded in the first partition.
+    assert_eq!(sclk.decode(25_600_000.0).unwrap(), "1/0000100000.000");
+    // Decoding rounds to the nearest tick.
+    assert_eq!(sclk.decode(25_625_663.6).unwrap(), "2/0000101100.064");
+
+    // Without a partition, the first partition containing the count is used.
+    assert_eq!(sclk.encode("101100.64").unwrap(), 25_625_664.0);
+    assert_eq!(sclk.encode("25000.128").unwrap(), 6_400_128.0);
+
+    // Invalid clock strings
+    assert!(sclk.encode("100500.0").is_err());
+    assert!(sclk.encode("1/200000.0").is_err());
+    assert!(sclk.encode("3/200000.0").is_err());
+    assert!(sclk.encode("0/1.0").is_err());
+    assert!(sclk.encode("1/1.256").is_err());
+    assert!(sclk.encode("1/1.2.3").is_err());
+    assert!(sclk.encode("1/one").is_err());
+    assert!(sclk.decode(-1.0).is_err());
+    assert!(sclk.decode(2e12).is_err());
+}
+
+#[test]
+fn sclk_epoch() {
+    let sclk = fixture();
+    let tt = |y, m, d, hh, mm, ss, nanos| {
+        Epoch::from_gregorian(y, m, d, hh, mm, ss, nanos, TimeScale::TT)
+    };
+
+    // First record, with a unit rate
+    let epoch = sclk.string_to_epoch("1/0000025000.128").unwrap();
+    assert_eq!(epoch, tt(2024, 1, 1, 6, 56, 40, 500_000_000));
+    assert_eq!(sclk.epoch_to_ticks(epoch).unwrap(), 6_400_128.0);
+    assert_eq!(sclk.epoch_to_string(epoch).unwrap(), "1/0000025000.128");
+
+    // Second record, where the clock is slow
+    let epoch = sclk.string_to_epoch("1/75000.0").unwrap();
+    assert!((epoch - tt(2024, 1, 1, 20, 50, 0, 25_000_000)).abs() <= 1 * Unit::Nanosecond);
+    assert_eq!(sclk.epoch_to_string(epoch).unwrap(), "1/0000075000.000");
+
+    // Third record, after the clock reset
+    let epoch = sclk.string_to_epoch("2/0000101000.000").unwrap();
+    assert_eq!(epoch, tt(2024, 1, 2, 4, 0, 0, 0));
+    let epoch = sclk.string_to_epoch("2/101100.64").unwrap();
+    assert_eq!(epoch, tt(2024, 1, 2, 4, 1, 40, 249_899_750));
+    assert_eq!(sclk.epoch_to_string(epoch).unwrap(), "2/0000101100.064");
+
+    // Continuous ticks between two ticks of the clock
+    let ticks = sclk.epoch_to_ticks(epoch + 1 * Unit::Millisecond).unwrap();
+    assert!((ticks - 25_625_664.256).abs() < 1e-3);
+    assert_eq!(
+        sclk.epoch_to_string(epoch + 1 * Unit::Millisecond).unwrap(),
+        "2/0000101100.064"
+    );
+
+    // Epochs and ticks outside of the clock
+    assert!(sclk.epoch_to_ticks(tt(2023, 12, 31, 0, 0, 0, 0)).is_err());
+    assert!(sclk.ticks_to_epoch(-1.0).is_err());
+}
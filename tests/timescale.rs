@@ -13,6 +13,14 @@ fn test_from_str() {
         ("GST", TimeScale::GST),
         ("BDT", TimeScale::BDT),
         ("QZSST", TimeScale::QZSST),
+        ("TCG", TimeScale::TCG),
+        ("TCB", TimeScale::TCB),
+        ("UT1", TimeScale::UT1),
+        ("GLONASST", TimeScale::GLONASST),
+        ("IRNWT", TimeScale::IRNWT),
+        ("LTC", TimeScale::LTC),
+        ("UTC-SLS", TimeScale::UTCSLS),
+        ("UTC-SMEAR", TimeScale::UTCSMEAR),
     ];
     for value in values {
         let (descriptor, expected) = value;
@@ -28,6 +36,8 @@ fn test_from_str() {
             TimeScale::GST => "GAL",
             TimeScale::BDT => "BDS",
             TimeScale::QZSST => "QZSS",
+            TimeScale::GLONASST => "GLO",
+            TimeScale::IRNWT => "IRN",
             _ => descriptor, // untouched
         };
         assert_eq!(format!("{:x}", ts), expected);
@@ -44,6 +54,8 @@ fn test_from_rinex_format() {
     assert_eq!(TimeScale::from_str("GAL"), Ok(TimeScale::GST));
     assert_eq!(TimeScale::from_str("BDS"), Ok(TimeScale::BDT));
     assert_eq!(TimeScale::from_str("QZSS"), Ok(TimeScale::QZSST));
+    assert_eq!(TimeScale::from_str("GLO"), Ok(TimeScale::GLONASST));
+    assert_eq!(TimeScale::from_str("IRN"), Ok(TimeScale::IRNWT));
     // Check error
     assert_eq!(TimeScale::from_str("FAK"), Err(ParsingError::TimeSystem));
 }
@@ -60,9 +72,179 @@ fn test_is_gnss() {
     assert!(!ts.is_gnss());
     let ts = TimeScale::QZSST;
     assert!(ts.is_gnss());
+    let ts = TimeScale::GLONASST;
+    assert!(ts.is_gnss());
+    let ts = TimeScale::IRNWT;
+    assert!(ts.is_gnss());
 }
 
 #[test]
 fn test_default() {
     assert_eq!(TimeScale::default(), TimeScale::TAI);
 }
+
+#[test]
+fn test_custom_time_scale() {
+    use hifitime::efmt::{consts::ISO8601, Formatter};
+    use hifitime::{CustomTimeScale, Duration, Epoch, TimeUnits, Unit};
+
+    /// A ground station clock with a bias, a drift and an aging term with respect to TAI.
+    struct AgingClock;
+
+    impl CustomTimeScale for AgingClock {
+        fn name(&self) -> &'static str {
+            "STN1"
+        }
+
+        fn reference_epoch(&self) -> Epoch {
+            Epoch::from_gregorian_tai_at_midnight(2024, 1, 1)
+        }
+
+        fn tai_offset(&self) -> Duration {
+            -250.microseconds()
+        }
+
+        fn rate(&self) -> f64 {
+            2e-9
+        }
+
+        fn offset_from_tai(&self, tai_elapsed: Duration) -> Duration {
+            let t = tai_elapsed.to_seconds();
+            self.tai_offset() + Unit::Second * (self.rate() * t + 1e-16 * t * t)
+        }
+    }
+
+    let clock = AgingClock;
+    let reference = clock.reference_epoch();
+    assert_eq!(reference.to_custom_duration(&clock), -250.microseconds());
+    assert_eq!(
+        Epoch::from_custom_duration(Duration::ZERO, &clock),
+        reference + 250.microseconds()
+    );
+
+    let epoch = Epoch::from_gregorian_utc_hms(2024, 7, 1, 12, 0, 0);
+    let elapsed = (epoch - reference).to_seconds();
+    let expected = elapsed - 250e-6 + 2e-9 * elapsed + 1e-16 * elapsed * elapsed;
+    assert!((epoch.to_custom_seconds(&clock) - expected).abs() < 1e-9);
+
+    // Round trip, including through the seconds representation
+    for days in [-365, -1, 0, 1, 100, 3650] {
+        let e = epoch + days * Unit::Day;
+        assert_eq!(
+            Epoch::from_custom_duration(e.to_custom_duration(&clock), &clock),
+            e
+        );
+    }
+    let rtn = Epoch::from_custom_seconds(epoch.to_custom_seconds(&clock), &clock);
+    assert!((rtn - epoch).abs() < 1 * Unit::Microsecond);
+
+    // The custom clock reading is shown on the TAI calendar, with the name of the time scale.
+    let custom = reference + 1 * Unit::Day;
+    assert_eq!(
+        format!(
+            "{}",
+            Formatter::to_custom_time_scale(custom, ISO8601, &clock)
+        ),
+        "2024-01-01T23:59:59.999923546 STN1"
+    );
+}
+
+#[test]
+fn test_gnss_time_corrections() {
+    use hifitime::{Epoch, GnssTimeCorrection, HifitimeError, TimeUnits, Unit};
+
+    let header = "     3.04           N: GNSS NAV DATA    M: MIXED            RINEX VERSION / TYPE
+GPUT  0.9313225746E-09-0.532907052E-14 233472 2240 G10    2 TIME SYSTEM CORR
+GAUT  0.1862645149D-08-0.888178420D-15 432000 2240        5 TIME SYSTEM CORR
+GPGA  0.2328306437E-09 0.266453526E-14 345600 2240        0 TIME SYSTEM CORR
+BDUT -0.9313225746E-09 0.000000000E+00     14  896        7 TIME SYSTEM CORR
+SBUT  0.0000000000E+00 0.000000000E+00      0    0        0 TIME SYSTEM CORR
+GLUT  0.9313225746E-09 0.000000000E+00      0    0        3 TIME SYSTEM CORR
+    18    18  2185     7                                    LEAP SECONDS
+                                                            END OF HEADER
+QZUT  0.9313225746E-09 0.000000000E+00      0    0        0 TIME SYSTEM CORR
+";
+    let corrections = GnssTimeCorrection::from_rinex_header(header).unwrap();
+    // SBAS is not supported, and nothing is parsed after the header.
+    assert_eq!(corrections.len(), 5);
+
+    let gput = corrections[0];
+    assert_eq!(gput.lhs, TimeScale::GPST);
+    assert_eq!(gput.rhs, TimeScale::UTC);
+    assert_eq!(gput.a0, 0.9313225746e-9);
+    assert_eq!(gput.a1, -0.532907052e-14);
+    assert_eq!(gput.utc_id, 2);
+    assert_eq!(
+        gput.reference_epoch,
+        Epoch::from_time_of_week(2240, 233_472 * 1_000_000_000, TimeScale::GPST)
+    );
+
+    // GPGA is broadcast by Galileo as GST - GPST.
+    let gpga = corrections[2];
+    assert_eq!(gpga.lhs, TimeScale::GST);
+    assert_eq!(gpga.rhs, TimeScale::GPST);
+
+    // BeiDou uses its own week numbers.
+    let bdut = corrections[3];
+    assert_eq!(bdut.lhs, TimeScale::BDT);
+    assert_eq!(
+        bdut.reference_epoch,
+        Epoch::from_time_of_week(896, 14 * 1_000_000_000, TimeScale::BDT)
+    );
+    assert_eq!(corrections[4].lhs, TimeScale::GLONASST);
+    assert_eq!(corrections[4].utc_id, 3);
+
+    // The correction is evaluated from the reference epoch, here one day later.
+    let gpst = gput.reference_epoch + 1.days();
+    assert_eq!(
+        gput.correction(gpst),
+        Unit::Second * (0.9313225746e-9 - 0.532907052e-14 * 86_400.0)
+    );
+    let utc = gpst
+        .to_time_scale_with_correction(TimeScale::UTC, &gput)
+        .unwrap();
+    assert_eq!(utc.time_scale, TimeScale::UTC);
+    assert_eq!(
+        gpst.to_time_scale(TimeScale::UTC) - utc,
+        gput.correction(gpst)
+    );
+    // Corrections apply in both directions.
+    let rtn = utc
+        .to_time_scale_with_correction(TimeScale::GPST, &gput)
+        .unwrap();
+    assert_eq!(rtn.time_scale, TimeScale::GPST);
+    assert!((rtn - gpst).abs() <= 1.nanoseconds());
+
+    // Between constellations
+    let gst = gpst
+        .to_time_scale_with_correction(TimeScale::GST, &gpga)
+        .unwrap();
+    assert_eq!(
+        gst - gpst.to_time_scale(TimeScale::GST),
+        gpga.correction(gst)
+    );
+
+    // The correction must relate both time scales.
+    assert!(gpst
+        .to_time_scale_with_correction(TimeScale::GST, &gput)
+        .is_none());
+    assert!(gpst
+        .to_time_scale_with_correction(TimeScale::UTC, &bdut)
+        .is_none());
+
+    // Invalid lines
+    assert!(matches!(
+        GnssTimeCorrection::from_rinex_str(
+            "SBUT  0.0000000000E+00 0.000000000E+00      0    0        0 TIME SYSTEM CORR"
+        ),
+        Err(HifitimeError::Parse {
+            source: ParsingError::UnsupportedTimeSystem,
+            ..
+        })
+    ));
+    assert!(GnssTimeCorrection::from_rinex_str(
+        "GPUT  0.93132257X6E-09-0.532907052E-14 233472 2240 G10    2 TIME SYSTEM CORR"
+    )
+    .is_err());
+    assert!(GnssTimeCorrection::from_rinex_str("GPUT").is_err());
+}
@@ -3,7 +3,7 @@
 fn test_ut1_from_file() {
     use core::str::FromStr;
     use hifitime::ut1::Ut1Provider;
-    use hifitime::Epoch;
+    use hifitime::{Epoch, HifitimeError, TimeScale};
 
     let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
 
@@ -18,9 +18,38 @@ fn test_ut1_from_file() {
     //
     let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
     assert_eq!(
-        format!("{:x}", epoch.to_ut1(provider)),
+        format!("{:x}", epoch.to_ut1(provider.clone())),
         "2022-01-03T03:05:06.679020600 TAI"
     );
+
+    // The fallible conversions round trip with the provided provider only.
+    let ut1_duration = epoch.try_to_ut1_duration(&provider).unwrap();
+    assert_eq!(
+        Epoch::from_duration(ut1_duration, TimeScale::UTC),
+        Epoch::from_str("2022-01-03T03:05:06.679020600 UTC").unwrap()
+    );
+    assert_eq!(
+        Epoch::try_from_ut1_duration(ut1_duration, &provider).unwrap(),
+        epoch
+    );
+
+    // The provider has no data before October 2021.
+    let before = Epoch::from_gregorian_utc_at_midnight(2021, 1, 1);
+    assert!(before.ut1_offset(provider.clone()).is_none());
+    assert!(matches!(
+        before.try_to_ut1_duration(&provider),
+        Err(HifitimeError::Ut1Unavailable { .. })
+    ));
+
+    // Without an installed provider, the UT1 time scale cannot be converted into exactly.
+    assert!(matches!(
+        epoch.try_to_time_scale(TimeScale::UT1),
+        Err(HifitimeError::Ut1Unavailable { .. })
+    ));
+    assert_eq!(
+        epoch.try_to_time_scale(TimeScale::TT),
+        Ok(epoch.to_time_scale(TimeScale::TT))
+    );
 }
 
 #[cfg(feature = "ut1")]
@@ -49,3 +78,135 @@ fn test_ut1_from_jpl() {
         "2022-01-03T03:05:43.789100000 TAI",
     );
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn test_delta_t_estimate_eop() {
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, TimeScale, Unit};
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    // The observed values of ΔT are within a few hundredths of a second of the EOP data, which ends with predictions in 2023.
+    let mut epoch = Epoch::from_gregorian_at_noon(2021, 10, 15, TimeScale::TT);
+    while epoch < Epoch::from_gregorian_at_noon(2022, 12, 31, TimeScale::TT) {
+        let delta_t_eop = epoch.to_tt_duration() - epoch.try_to_ut1_duration(&provider).unwrap();
+        let delta_t = epoch.delta_t_estimate();
+        assert!(
+            (delta_t - delta_t_eop).abs() < 50 * Unit::Millisecond,
+            "{epoch}: {delta_t} {delta_t_eop}"
+        );
+        epoch += Unit::Day * 7;
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_sidereal_time() {
+    use core::f64::consts::TAU;
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, HifitimeError, Unit};
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 6, 1, 12, 0, 0);
+
+    let era = epoch.earth_rotation_angle(&provider).unwrap();
+    let gmst82 = epoch
+        .greenwich_mean_sidereal_time_iau1982(&provider)
+        .unwrap();
+    let gmst00 = epoch
+        .greenwich_mean_sidereal_time_iau2000(&provider)
+        .unwrap();
+    let gmst06 = epoch
+        .greenwich_mean_sidereal_time_iau2006(&provider)
+        .unwrap();
+    let gast = epoch.greenwich_apparent_sidereal_time(&provider).unwrap();
+    for angle in [era, gmst82, gmst00, gmst06, gast] {
+        assert!((0.0..TAU).contains(&angle));
+    }
+
+    // GMST is ahead of the ERA by the accumulated precession in right ascension, about 17 arcminutes in 2022.
+    assert!((gmst06 - era - 0.005_01).abs() < 1e-5, "{}", gmst06 - era);
+    // Both GMST models differ by less than a tenth of an arcsecond, mostly from the updated precession rate.
+    assert!((gmst06 - gmst82).abs() < 5e-7);
+    // The IAU 2000 and 2006 GMST models differ by less than a milliarcsecond.
+    assert!((gmst06 - gmst00).abs() < 5e-9);
+    // The equation of the equinoxes is at most about 1.2 seconds of time.
+    assert!((gast - gmst00 - epoch.equation_of_the_equinoxes()).abs() < 1e-12);
+    assert!(epoch.equation_of_the_equinoxes().abs() < 1e-4);
+
+    // Local sidereal times only add the east longitude.
+    let lmst = epoch.local_mean_sidereal_time(-104.9, &provider).unwrap();
+    let last = epoch
+        .local_apparent_sidereal_time(-104.9, &provider)
+        .unwrap();
+    assert!(
+        ((lmst - gmst06).rem_euclid(TAU) - (-104.9_f64).to_radians().rem_euclid(TAU)).abs() < 1e-12
+    );
+    assert!(
+        ((last - gast).rem_euclid(TAU) - (-104.9_f64).to_radians().rem_euclid(TAU)).abs() < 1e-12
+    );
+
+    // The Earth rotates by slightly more than a turn in a day.
+    let era_next = (epoch + Unit::Day * 1)
+        .earth_rotation_angle(&provider)
+        .unwrap();
+    assert!(((era_next - era).rem_euclid(TAU) - 0.017_202_4).abs() < 1e-6);
+
+    // Without UT1 data, the sidereal times are not computed with UT1 = TAI.
+    let before = Epoch::from_gregorian_utc_at_midnight(2021, 1, 1);
+    assert!(matches!(
+        before.greenwich_apparent_sidereal_time(&provider),
+        Err(HifitimeError::Ut1Unavailable { .. })
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_local_solar_time() {
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, HifitimeError, TimeUnits, Unit};
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let epoch = Epoch::from_gregorian_utc_hms(2022, 6, 1, 22, 15, 0);
+
+    // The local mean solar time follows UT1, which differs from UTC by up to 0.9 seconds.
+    let ut1_utc = epoch.try_to_ut1_duration(&provider).unwrap()
+        - epoch.to_time_scale(hifitime::TimeScale::UTC).duration;
+    assert!(ut1_utc.abs() > Unit::Millisecond);
+    assert!(ut1_utc.abs() < 0.9.seconds());
+    let lmst = epoch.to_local_mean_solar_time(-90.0, &provider).unwrap();
+    assert_eq!(lmst, 16.hours() + 15.minutes() + ut1_utc);
+    assert_eq!(
+        lmst - epoch.to_local_mean_solar_time_from_utc(-90.0),
+        ut1_utc
+    );
+    assert_eq!(
+        epoch.to_local_true_solar_time(-90.0, &provider).unwrap(),
+        lmst + epoch.equation_of_time()
+    );
+
+    // Next 10:30 descending node of a sun-synchronous orbit over 120 degrees East
+    let node = epoch
+        .next_local_mean_solar_time(120.0, 10.hours() + 30.minutes(), &provider)
+        .unwrap();
+    let lmst = node.to_local_mean_solar_time(120.0, &provider).unwrap();
+    assert!((lmst - 10.hours() - 30.minutes()).abs() < 1.microseconds());
+    assert!((node - Epoch::from_gregorian_utc_hms(2022, 6, 2, 2, 30, 0)).abs() < 0.9.seconds());
+    let ltst = epoch
+        .next_local_true_solar_time(120.0, 10.hours() + 30.minutes(), &provider)
+        .unwrap()
+        .to_local_true_solar_time(120.0, &provider)
+        .unwrap();
+    assert!((ltst - 10.hours() - 30.minutes()).abs() < 1.microseconds());
+
+    // Without UT1 data, the local solar times are not computed with UT1 = UTC.
+    let before = Epoch::from_gregorian_utc_at_midnight(2021, 1, 1);
+    assert!(matches!(
+        before.to_local_mean_solar_time(0.0, &provider),
+        Err(HifitimeError::Ut1Unavailable { .. })
+    ));
+    assert!(matches!(
+        before.next_local_true_solar_time(0.0, 12.hours(), &provider),
+        Err(HifitimeError::Ut1Unavailable { .. })
+    ));
+}
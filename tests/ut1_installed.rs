@@ -0,0 +1,40 @@
+// The installed UT1 provider is global to the process, so this test has its own binary.
+#[cfg(feature = "ut1")]
+#[test]
+fn test_ut1_installed() {
+    use core::str::FromStr;
+    use hifitime::efmt::{Format, Formatter};
+    use hifitime::ut1::Ut1Provider;
+    use hifitime::{Epoch, TimeScale, Unit};
+
+    let provider = Ut1Provider::from_eop_file("data/eop-2021-10-12--2023-01-04.short").unwrap();
+    let epoch = Epoch::from_str("2022-01-03 03:05:06.7891").unwrap();
+    let ut1_epoch = Epoch::from_duration(
+        epoch.try_to_ut1_duration(&provider).unwrap(),
+        TimeScale::UT1,
+    );
+    assert_eq!(format!("{ut1_epoch}"), "2022-01-03T03:05:06.679020600 UT1");
+
+    // Once installed, the provider is used to convert to and from the UT1 time scale.
+    provider.install();
+    assert_eq!(epoch.to_time_scale(TimeScale::UT1), ut1_epoch);
+    assert_eq!(epoch.try_to_time_scale(TimeScale::UT1), Ok(ut1_epoch));
+    assert_eq!(ut1_epoch.to_time_scale(TimeScale::UTC), epoch);
+    assert_eq!(ut1_epoch, epoch);
+    assert!(ut1_epoch < epoch + 1 * Unit::Nanosecond);
+
+    let parsed = Epoch::from_str("2022-01-03T03:05:06.679020600 UT1").unwrap();
+    assert_eq!(parsed.time_scale, TimeScale::UT1);
+    assert_eq!(parsed, epoch);
+
+    let fmt = Formatter::new(parsed, Format::from_str("%H:%M:%S.%f %T").unwrap());
+    assert_eq!(format!("{fmt}"), "03:05:06.679020600 UT1");
+
+    // Without a provider, UT1 is assumed to match UTC, unless the conversion is fallible.
+    assert!(Ut1Provider::uninstall().is_some());
+    assert_eq!(
+        format!("{}", epoch.to_time_scale(TimeScale::UT1)),
+        "2022-01-03T03:05:06.789100000 UT1"
+    );
+    assert!(epoch.try_to_time_scale(TimeScale::UT1).is_err());
+}